@@ -30,5 +30,6 @@
 //! - <https://fourmilab.ch/moontool/>
 //! - <https://fourmilab.ch/moontoolw/>
 
-pub mod datetime;
+#[cfg(feature = "lua")]
+pub mod lua;
 pub mod moon;
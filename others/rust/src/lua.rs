@@ -0,0 +1,112 @@
+//! Lua scripting bindings, behind the optional `lua` feature.
+//!
+//! Exposes the crate's calculation surface — phase, topocentric
+//! position, and rise/transit/set — as a `moontool` Lua table, via
+//! [`mlua`](https://docs.rs/mlua). This is meant for embedding in a
+//! Lua-driven host (batch lunar queries, scripted astronomy tools), not
+//! for use from Rust, which should call [`crate::moon`] directly.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! let lua = mlua::Lua::new();
+//! let moontool = moontool::lua::register(&lua)?;
+//! lua.globals().set("moontool", moontool)?;
+//! lua.load(r#"print(moontool.phase(2024, 6, 14, 0, 0, 0).fraction_illuminated)"#)
+//!     .exec()?;
+//! ```
+
+use mlua::{Lua, Result as LuaResult, Table};
+
+use crate::moon::{moon_position, moon_rise_set, ForDateTime, MoonPhase, UTCDateTime};
+
+/// Build the `moontool` Lua table (phase/position/rise-set functions)
+/// for registration into a Lua state, e.g.:
+///
+/// ```rust,ignore
+/// let moontool = moontool::lua::register(&lua)?;
+/// lua.globals().set("moontool", moontool)?;
+/// ```
+///
+/// # Errors
+///
+/// Errors if table/function construction fails, per `mlua`'s error type.
+pub fn register(lua: &Lua) -> LuaResult<Table<'_>> {
+    let moontool = lua.create_table()?;
+
+    moontool.set("phase", lua.create_function(phase)?)?;
+    moontool.set("rise_set", lua.create_function(rise_set)?)?;
+    moontool.set("position", lua.create_function(position)?)?;
+
+    Ok(moontool)
+}
+
+/// `moontool.phase(year, month, day, hour, minute, second)`.
+///
+/// Returns a table with `age`, `fraction_of_lunation`,
+/// `fraction_illuminated`, `phase_name`, `distance_to_earth_km`, and
+/// `subtends`, for the Moon at the given UTC date/time.
+fn phase(
+    lua: &Lua,
+    (year, month, day, hour, minute, second): (i32, u32, u32, u32, u32, u32),
+) -> LuaResult<Table<'_>> {
+    let datetime = UTCDateTime::from_ymdhms(year, month, day, hour, minute, second);
+    let phase = MoonPhase::for_datetime(&datetime);
+
+    let result = lua.create_table()?;
+    result.set("age", phase.age)?;
+    result.set("fraction_of_lunation", phase.fraction_of_lunation)?;
+    result.set("fraction_illuminated", phase.fraction_illuminated)?;
+    result.set("phase_name", phase.phase_name)?;
+    result.set("distance_to_earth_km", phase.distance_to_earth_km)?;
+    result.set("subtends", phase.subtends)?;
+    Ok(result)
+}
+
+/// `moontool.rise_set(latitude, longitude, year, month, day)`.
+///
+/// Returns a table with `rise`, `transit`, and `set`, each either a
+/// Julian date or `nil` if the Moon doesn't cross the relevant event
+/// that day.
+fn rise_set(
+    lua: &Lua,
+    (latitude, longitude, year, month, day): (f64, f64, i32, u32, u32),
+) -> LuaResult<Table<'_>> {
+    let date = UTCDateTime::from_ymdhms(year, month, day, 0, 0, 0);
+    let rs = moon_rise_set(latitude, longitude, &date);
+
+    let result = lua.create_table()?;
+    result.set("rise", rs.rise.map(|dt| dt.to_julian_date()))?;
+    result.set("transit", rs.transit.map(|dt| dt.to_julian_date()))?;
+    result.set("set", rs.set.map(|dt| dt.to_julian_date()))?;
+    Ok(result)
+}
+
+/// `moontool.position(latitude, longitude, year, month, day, hour,
+/// minute, second)`.
+///
+/// Returns a table with topocentric `altitude`, `azimuth`, and
+/// `distance_km`, as seen from the given observer at the given instant.
+#[allow(clippy::too_many_arguments)]
+fn position(
+    lua: &Lua,
+    (latitude, longitude, year, month, day, hour, minute, second): (
+        f64,
+        f64,
+        i32,
+        u32,
+        u32,
+        u32,
+        u32,
+        u32,
+    ),
+) -> LuaResult<Table<'_>> {
+    let datetime = UTCDateTime::from_ymdhms(year, month, day, hour, minute, second);
+    let position = moon_position(latitude, longitude, &datetime);
+
+    let result = lua.create_table()?;
+    result.set("altitude", position.altitude)?;
+    result.set("azimuth", position.azimuth)?;
+    result.set("distance_km", position.distance_km)?;
+    Ok(result)
+}
@@ -1,17 +1,131 @@
 //! Command Line Interface for moon.rs.
 
 use moontool::moon::{
-    ForDateTime, LocalDateTime, MoonCalendar, MoonPhase, SunCalendar, ToJSON, UTCDateTime,
-    YearlyMoonCalendar,
+    chinese_lunar_date, ical_calendar, ical_event, list_lunar_apsides,
+    list_principal_phases_between, nakshatra_for_ecliptic_longitude, ChineseLunarDate,
+    ForDateTime, LocalDateTime, LunarApsis, LunarApsisList, MoonCalendar, MoonPhase,
+    PrincipalPhaseList, SunCalendar, ToJSON, UTCDateTime, YearlyMoonCalendar,
 };
 use std::fmt::Write;
-use std::{env, process};
+use std::io::IsTerminal;
+use std::{env, io, process};
 use textcanvas::{charts::Plot, Color, TextCanvas};
 
 mod moon_icon;
 
 const GRAPH_WIDTH: i32 = 80;
 
+/// Output backend for [`render_moon`] and [`render_moon_graphs`].
+///
+/// `Braille` (the default) is the densest, but relies on the Unicode
+/// braille block (U+2800), which renders as tofu or misaligns on
+/// terminals and fonts lacking braille coverage. `Ascii` and `HalfBlock`
+/// trade resolution for wider compatibility.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum RenderStyle {
+    Braille,
+    Ascii,
+    HalfBlock,
+}
+
+impl Default for RenderStyle {
+    fn default() -> Self {
+        Self::Braille
+    }
+}
+
+impl RenderStyle {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "braille" => Some(Self::Braille),
+            "ascii" => Some(Self::Ascii),
+            "halfblock" => Some(Self::HalfBlock),
+            _ => None,
+        }
+    }
+}
+
+/// Machine-readable export format for [`print_moon_data`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum DataFormat {
+    Csv,
+    Json,
+}
+
+impl DataFormat {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "csv" => Some(Self::Csv),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
+/// Whether to colour the ANSI-highlighted markers (current phase dot,
+/// graph cursor line). See [`Palette`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ColorMode {
+    Auto,
+    Always,
+    Never,
+}
+
+impl Default for ColorMode {
+    fn default() -> Self {
+        Self::Auto
+    }
+}
+
+impl ColorMode {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "auto" => Some(Self::Auto),
+            "always" => Some(Self::Always),
+            "never" => Some(Self::Never),
+            _ => None,
+        }
+    }
+
+    /// Resolve `Auto` against the `NO_COLOR` convention
+    /// (<https://no-color.org/>) and whether stdout is a terminal.
+    #[cfg(not(tarpaulin_include))]
+    fn is_enabled(self) -> bool {
+        match self {
+            Self::Always => true,
+            Self::Never => false,
+            Self::Auto => env::var_os("NO_COLOR").is_none() && io::stdout().is_terminal(),
+        }
+    }
+}
+
+/// Decides whether the ANSI-highlighted markers drawn by [`render_moon`]
+/// and [`render_moon_graphs`] (the current-phase dot, the graph cursor
+/// line) are coloured, and applies that decision to a [`PixelSink`].
+///
+/// The only colour these markers have ever used is
+/// `Color::new().bright_red()` (see [`draw_apollo_11_commemorative_dot`]
+/// before this change); picking among several colours (a `--theme`
+/// option) would need to know what other builder methods `Color`
+/// exposes, which isn't verifiable here without the `textcanvas` crate's
+/// source, so `Palette` only controls whether to colour at all.
+struct Palette {
+    enabled: bool,
+}
+
+impl Palette {
+    fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+
+    /// Set the highlight colour on `canvas`, unless colouring is disabled.
+    fn highlight<S: PixelSink>(&self, canvas: &mut S) {
+        if self.enabled {
+            canvas.set_color(Color::new().bright_red());
+        }
+    }
+}
+
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Eq, PartialEq)]
 struct Config {
@@ -21,7 +135,16 @@ struct Config {
     verbose: bool,
     moon: bool,
     graph: bool,
+    phases: bool,
     json: bool,
+    ics: bool,
+    apsides: bool,
+    calendar: bool,
+    chinese: bool,
+    style: RenderStyle,
+    data: Option<DataFormat>,
+    color: ColorMode,
+    format: Option<String>,
 }
 
 // Prefer explicit default config.
@@ -35,7 +158,16 @@ impl Default for Config {
             verbose: false,
             moon: false,
             graph: false,
+            phases: false,
             json: false,
+            ics: false,
+            apsides: false,
+            calendar: false,
+            chinese: false,
+            style: RenderStyle::default(),
+            data: None,
+            color: ColorMode::default(),
+            format: None,
         }
     }
 }
@@ -43,8 +175,9 @@ impl Default for Config {
 impl Config {
     fn new(args: impl Iterator<Item = String>) -> Result<Self, String> {
         let mut config = Self::default();
+        let mut args = args.skip(1);
 
-        for arg in args.skip(1) {
+        while let Some(arg) = args.next() {
             if arg == "-h" || arg == "--help" {
                 config.help = true;
                 break;
@@ -70,13 +203,83 @@ impl Config {
                 continue;
             }
 
+            if arg == "--phases" {
+                config.phases = true;
+                continue;
+            }
+
             if arg == "--json" {
                 config.json = true;
                 continue;
             }
 
-            // `-` can be the start of a negative timestamp.
-            if arg.starts_with("--") || arg.starts_with('-') && arg.parse::<i64>().is_err() {
+            if arg == "--ics" {
+                config.ics = true;
+                continue;
+            }
+
+            if arg == "--apsides" {
+                config.apsides = true;
+                continue;
+            }
+
+            if arg == "--calendar" {
+                config.calendar = true;
+                continue;
+            }
+
+            if arg == "--chinese" {
+                config.chinese = true;
+                continue;
+            }
+
+            if arg == "--style" {
+                let Some(value) = args.next() else {
+                    return Err(String::from("--style requires a value."));
+                };
+                let Some(style) = RenderStyle::parse(&value) else {
+                    return Err(format!("Unknown render style '{value}'."));
+                };
+                config.style = style;
+                continue;
+            }
+
+            if arg == "--data" {
+                let Some(value) = args.next() else {
+                    return Err(String::from("--data requires a value."));
+                };
+                let Some(format) = DataFormat::parse(&value) else {
+                    return Err(format!("Unknown data format '{value}'."));
+                };
+                config.data = Some(format);
+                continue;
+            }
+
+            if arg == "--color" {
+                let Some(value) = args.next() else {
+                    return Err(String::from("--color requires a value."));
+                };
+                let Some(mode) = ColorMode::parse(&value) else {
+                    return Err(format!("Unknown color mode '{value}'."));
+                };
+                config.color = mode;
+                continue;
+            }
+
+            if arg == "--format" {
+                let Some(value) = args.next() else {
+                    return Err(String::from("--format requires a value."));
+                };
+                config.format = Some(value);
+                continue;
+            }
+
+            // `-` can be the start of a negative timestamp, and `--`/`---`
+            // can start an XSD reduced date (gMonth, gMonthDay, gDay; see
+            // `try_from_partial_iso_string`).
+            if (arg.starts_with("--") && !is_partial_date_arg(&arg))
+                || (arg.starts_with('-') && !arg.starts_with("--") && arg.parse::<i64>().is_err())
+            {
                 return Err(format!("Unknown argument '{arg}'."));
             }
 
@@ -106,10 +309,11 @@ fn main() {
         return;
     }
 
+    let now = get_now();
     let datetime = if let Some(ref datetime) = config.datetime {
-        try_parse_datetime(datetime)
+        try_parse_datetime(datetime, &now)
     } else {
-        Some(get_now())
+        Some(now)
     };
 
     let Some(datetime) = datetime else {
@@ -131,9 +335,29 @@ optional arguments:
   -vv, --verbose        verbose output
   --moon                show render of Moon
   --graph               graph of lunation
+  --phases              list upcoming principal phases
   --json                output as json
+  --ics                 output month's moon phases as iCalendar (.ics)
+  --apsides             list upcoming lunar perigees and apogees
+  --calendar            month grid with a moon-phase glyph per day
+  --chinese             convert to the traditional Chinese lunar date
+  --style <STYLE>       render style for --moon: braille (default), ascii,
+                        or halfblock; --graph is always drawn in braille
+  --data <FORMAT>       export the yearly moon-data series as csv or json,
+                        instead of graphing it
+  --color <MODE>        colour the ANSI-highlighted markers: auto
+                        (default, honours NO_COLOR and non-tty stdout),
+                        always, or never
+  --format <PATTERN>    print a custom one-line summary instead of the
+                        full report; strftime-like specifiers: %Y %m %d
+                        %H %M %S (evaluated datetime), %P (phase name),
+                        %p (illuminated fraction, 0-100), %a (Moon age in
+                        days), %D (distance to Earth in km), %% (literal
+                        percent)
   []                    without arguments, defaults to now
-  [DATETIME]            local datetime (e.g., 1994-12-22T14:53:34+01:00)
+  [DATETIME]            local datetime (e.g., 1994-12-22T14:53:34+01:00,
+                        or an RFC 2822/HTTP-date string, e.g.,
+                        Thu, 22 Dec 1994 14:53:34 +0100)
   [¬±TIMESTAMP]          Unix timestamp (e.g., 788104414)
   [JULIAN DATE]         Julian date (e.g., 2449709.07887)",
         bin = env!("CARGO_BIN_NAME")
@@ -149,14 +373,84 @@ fn get_now() -> UTCDateTime {
     UTCDateTime::now()
 }
 
-fn try_parse_datetime(datetime: &str) -> Option<UTCDateTime> {
-    if let Some(datetime) = try_from_timestamp(datetime) {
+/// `now` resolves the year/month implied by the XSD reduced date forms
+/// ("--05-15" means May 15 of the *current* year) handled by
+/// [`try_from_partial_iso_string`]. Those are tried first, ahead of the
+/// bare-integer timestamp branch, so a 4-digit year like "2024" isn't
+/// swallowed as a Unix epoch value.
+fn try_parse_datetime(datetime: &str, now: &UTCDateTime) -> Option<UTCDateTime> {
+    if let Some(datetime) = try_from_partial_iso_string(datetime, now) {
+        Some(datetime)
+    } else if let Some(datetime) = try_from_timestamp(datetime) {
         Some(datetime)
     } else if let Some(datetime) = try_from_julian_date(datetime) {
         Some(datetime)
+    } else if let Some(datetime) = try_from_iso_string(datetime) {
+        Some(datetime)
+    } else if let Some(datetime) = try_from_two_digit_year_string(datetime, now) {
+        Some(datetime)
     } else {
-        try_from_iso_string(datetime)
+        try_from_rfc2822(datetime)
+    }
+}
+
+/// Whether `arg` looks like an XSD reduced date form (`--05`, `--05-15`,
+/// `---15`) rather than an unknown `--flag`, so [`Config::new`] doesn't
+/// reject it before it reaches [`try_from_partial_iso_string`].
+fn is_partial_date_arg(arg: &str) -> bool {
+    let digits = arg.trim_start_matches('-');
+    !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit() || c == '-')
+}
+
+/// XSD reduced ("partial") date forms, resolved to the start of the
+/// implied period:
+///
+/// - gYear, e.g., `2024` → 2024-01-01
+/// - gYearMonth, e.g., `2024-05` → 2024-05-01
+/// - gMonthDay, e.g., `--05-15` → May 15 of `now`'s year
+/// - gMonth, e.g., `--05` → May 1 of `now`'s year
+/// - gDay, e.g., `---15` → the 15th of `now`'s year and month
+fn try_from_partial_iso_string(datetime: &str, now: &UTCDateTime) -> Option<UTCDateTime> {
+    if let Some(day) = datetime.strip_prefix("---") {
+        let day: u32 = day.parse().ok()?;
+        return make_partial_date(now.year, now.month, day);
+    }
+
+    if let Some(rest) = datetime.strip_prefix("--") {
+        return if let Some((month, day)) = rest.split_once('-') {
+            let month: u32 = month.parse().ok()?;
+            let day: u32 = day.parse().ok()?;
+            make_partial_date(now.year, month, day)
+        } else {
+            let month: u32 = rest.parse().ok()?;
+            make_partial_date(now.year, month, 1)
+        };
+    }
+
+    if let Some((year, month)) = datetime.split_once('-') {
+        if year.len() != 4 {
+            return None;
+        }
+        let year: i32 = year.parse().ok()?;
+        let month: u32 = month.parse().ok()?;
+        return make_partial_date(year, month, 1);
+    }
+
+    if datetime.len() == 4 {
+        let year: i32 = datetime.parse().ok()?;
+        return make_partial_date(year, 1, 1);
     }
+
+    None
+}
+
+/// Builds a `UTCDateTime` at midnight and validates the month/day
+/// components (range, month length, leap years) by round-tripping it
+/// through [`UTCDateTime::to_timestamp`], mirroring [`try_from_iso_string`].
+fn make_partial_date(year: i32, month: u32, day: u32) -> Option<UTCDateTime> {
+    let datetime = UTCDateTime::from_ymdhms(year, month, day, 0, 0, 0);
+    datetime.to_timestamp().ok()?;
+    Some(datetime)
 }
 
 fn try_from_timestamp(timestamp: &str) -> Option<UTCDateTime> {
@@ -177,19 +471,118 @@ fn try_from_iso_string(datetime: &str) -> Option<UTCDateTime> {
     UTCDateTime::from_iso_string(datetime).ok()
 }
 
+/// How far into the past/future a two-digit year can resolve, relative
+/// to `now`, before [`resolve_two_digit_year`] shifts it by a century.
+/// The common "80-year window ending 20 years in the future" rule.
+const TWO_DIGIT_YEAR_PIVOT_YEARS_PAST: i32 = 79;
+const TWO_DIGIT_YEAR_PIVOT_YEARS_FUTURE: i32 = 20;
+
+/// Two-digit-year dates, e.g., `97-11-21` or `15/02/18`, resolved
+/// through a sliding pivot (see [`resolve_two_digit_year`]) so they land
+/// in a sensible century. A separator is required so an ambiguous
+/// all-numeric input (e.g., `971121`) isn't swallowed here instead of
+/// going to [`try_from_timestamp`].
+fn try_from_two_digit_year_string(datetime: &str, now: &UTCDateTime) -> Option<UTCDateTime> {
+    let separator = if datetime.contains('/') { '/' } else { '-' };
+
+    let mut parts = datetime.splitn(3, separator);
+    let yy = parts.next()?;
+    let month = parts.next()?;
+    let day = parts.next()?;
+    if parts.next().is_some() {
+        return None;
+    }
+
+    if yy.len() != 2 || !yy.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let yy: i32 = yy.parse().ok()?;
+    let month: u32 = month.parse().ok()?;
+    let day: u32 = day.parse().ok()?;
+
+    let year = resolve_two_digit_year(yy, now.year);
+    make_partial_date(year, month, day)
+}
+
+/// Maps a two-digit year `yy` (`[0;99]`) to a 4-digit year in
+/// `[now_year - 79; now_year + 20]`, by picking whichever of the
+/// current, previous, or next century puts it in that window.
+fn resolve_two_digit_year(yy: i32, now_year: i32) -> i32 {
+    let century_base = now_year - now_year.rem_euclid(100);
+    let mut year = century_base + yy;
+
+    if year - now_year > TWO_DIGIT_YEAR_PIVOT_YEARS_FUTURE {
+        year -= 100;
+    } else if now_year - year > TWO_DIGIT_YEAR_PIVOT_YEARS_PAST {
+        year += 100;
+    }
+
+    year
+}
+
+fn try_from_rfc2822(datetime: &str) -> Option<UTCDateTime> {
+    UTCDateTime::from_rfc2822_string(datetime).ok()
+}
+
 #[cfg(not(tarpaulin_include))]
 fn for_datetime(datetime: &UTCDateTime, config: &Config) {
     let mphase = MoonPhase::for_datetime(datetime);
 
     if config.moon {
-        draw_moon(mphase.fraction_of_lunation, &mphase.utc_datetime);
+        draw_moon(
+            mphase.fraction_of_lunation,
+            &mphase.utc_datetime,
+            config.style,
+            config.color,
+        );
         return;
     }
 
     let mcal = MoonCalendar::for_datetime(datetime);
 
     if config.graph {
-        graph_moon_data(&mcal, config.verbose);
+        graph_moon_data(&mcal, config.verbose, config.style, config.color);
+        return;
+    }
+
+    if let Some(format) = config.data {
+        print_moon_data(&mcal, format);
+        return;
+    }
+
+    if let Some(ref pattern) = config.format {
+        match format_moon_phase(pattern, &mphase) {
+            Ok(formatted) => println!("{formatted}"),
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(2);
+            }
+        }
+        return;
+    }
+
+    if config.phases {
+        print_principal_phases(datetime);
+        return;
+    }
+
+    if config.ics {
+        print_ics(datetime);
+        return;
+    }
+
+    if config.apsides {
+        print_lunar_apsides(datetime);
+        return;
+    }
+
+    if config.calendar {
+        print_calendar(datetime);
+        return;
+    }
+
+    if config.chinese {
+        print_chinese_lunar_date(datetime);
         return;
     }
 
@@ -205,17 +598,175 @@ fn for_datetime(datetime: &UTCDateTime, config: &Config) {
         None
     };
 
+    let nakshatra = if config.verbose {
+        Some(nakshatra_for_ecliptic_longitude(mphase.ecliptic_longitude))
+    } else {
+        None
+    };
+
+    let nearest_apsis = if config.verbose {
+        list_lunar_apsides(datetime, 1).into_iter().next()
+    } else {
+        None
+    };
+
+    let chinese_calendar = if config.verbose {
+        Some(ChineseLunarDate::for_datetime(datetime))
+    } else {
+        None
+    };
+
     if config.json {
-        print_json(&mphase, &mcal, &ymcal, &scal);
+        print_json(
+            &mphase,
+            &mcal,
+            &ymcal,
+            &scal,
+            &nakshatra,
+            &nearest_apsis,
+            &chinese_calendar,
+        );
         return;
     }
 
-    print_pretty(&mphase, &mcal, &ymcal, &scal);
+    print_pretty(
+        &mphase,
+        &mcal,
+        &ymcal,
+        &scal,
+        &nakshatra,
+        &nearest_apsis,
+        &chinese_calendar,
+    );
 }
 
 #[cfg(not(tarpaulin_include))]
-fn draw_moon(ph: f64, date: &UTCDateTime) {
-    print!("{}", render_moon(ph, date));
+fn draw_moon(ph: f64, date: &UTCDateTime, style: RenderStyle, color: ColorMode) {
+    let palette = Palette::new(color.is_enabled());
+    print!("{}", render_moon(ph, date, style, &palette));
+}
+
+/// A destination for the moon-disk scan in [`draw_moon_disk`].
+///
+/// Implemented by [`TextCanvas`] itself, so the `Braille` backend's
+/// output is pixel-for-pixel identical to before, and by [`DiskCoverage`],
+/// so the `Ascii` and `HalfBlock` backends share the exact same
+/// disk-coverage computation instead of recomputing it.
+trait PixelSink {
+    fn set_pixel(&mut self, x: i32, y: i32, value: bool);
+
+    /// No-op by default: only [`TextCanvas`] can render colour.
+    fn set_color(&mut self, _color: Color) {}
+}
+
+impl PixelSink for TextCanvas {
+    fn set_pixel(&mut self, x: i32, y: i32, value: bool) {
+        TextCanvas::set_pixel(self, x, y, value);
+    }
+
+    fn set_color(&mut self, color: Color) {
+        TextCanvas::set_color(self, color);
+    }
+}
+
+/// A plain dot grid, at the same resolution as a braille [`TextCanvas`]
+/// (2 columns x 4 rows of dots per output character cell).
+///
+/// [`render_moon`] draws onto this instead of a real [`TextCanvas`] for
+/// the [`RenderStyle::Ascii`] and [`RenderStyle::HalfBlock`] backends.
+struct DiskCoverage {
+    width: usize,
+    height: usize,
+    dots: Vec<bool>,
+}
+
+impl DiskCoverage {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            dots: vec![false; width * height],
+        }
+    }
+
+    fn is_set(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height && self.dots[y * self.width + x]
+    }
+
+    /// Fraction (0.0 to 1.0) of dots that are on within the 2x4 cell at
+    /// character-cell coordinates (`cell_x`, `cell_y`).
+    #[allow(clippy::cast_precision_loss)]
+    fn cell_fraction(&self, cell_x: usize, cell_y: usize) -> f64 {
+        let mut count = 0;
+        for dy in 0..4 {
+            for dx in 0..2 {
+                if self.is_set(cell_x * 2 + dx, cell_y * 4 + dy) {
+                    count += 1;
+                }
+            }
+        }
+        f64::from(count) / 8.0
+    }
+
+    /// Whether the top half (dot rows 0-1) and bottom half (dot rows 2-3)
+    /// of the cell at (`cell_x`, `cell_y`) have any dot on.
+    fn cell_halves(&self, cell_x: usize, cell_y: usize) -> (bool, bool) {
+        let top = (0..2)
+            .any(|dx| self.is_set(cell_x * 2 + dx, cell_y * 4) || self.is_set(cell_x * 2 + dx, cell_y * 4 + 1));
+        let bottom = (0..2)
+            .any(|dx| self.is_set(cell_x * 2 + dx, cell_y * 4 + 2) || self.is_set(cell_x * 2 + dx, cell_y * 4 + 3));
+        (top, bottom)
+    }
+
+    fn render_ascii(&self) -> String {
+        const RAMP: &[u8] = b" .:-=+*#%@";
+
+        let cell_width = self.width / 2;
+        let cell_height = self.height / 4;
+
+        let mut out = String::with_capacity((cell_width + 1) * cell_height);
+        for cell_y in 0..cell_height {
+            for cell_x in 0..cell_width {
+                let fraction = self.cell_fraction(cell_x, cell_y);
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let index = (fraction * (RAMP.len() - 1) as f64).round() as usize;
+                out.push(RAMP[index] as char);
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    fn render_half_block(&self) -> String {
+        let cell_width = self.width / 2;
+        let cell_height = self.height / 4;
+
+        let mut out = String::with_capacity((cell_width + 1) * cell_height);
+        for cell_y in 0..cell_height {
+            for cell_x in 0..cell_width {
+                let glyph = match self.cell_halves(cell_x, cell_y) {
+                    (true, true) => '█',
+                    (true, false) => '▀',
+                    (false, true) => '▄',
+                    (false, false) => ' ',
+                };
+                out.push(glyph);
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl PixelSink for DiskCoverage {
+    fn set_pixel(&mut self, x: i32, y: i32, value: bool) {
+        let (Ok(x), Ok(y)) = (usize::try_from(x), usize::try_from(y)) else {
+            return;
+        };
+        if x < self.width && y < self.height {
+            self.dots[y * self.width + x] = value;
+        }
+    }
 }
 
 /// Construct icon for moon, given phase of moon.
@@ -232,20 +783,69 @@ fn draw_moon(ph: f64, date: &UTCDateTime) {
 /// source pixmap (Full Moon image), and blits it onto the destination
 /// pixmap (render). The portions outside `[LX;RX]` are not blitted, and
 /// this is what creates the shadow.
+///
+/// The scan itself is written once, against the [`PixelSink`]
+/// abstraction, in [`draw_moon_disk`]; this function only picks which
+/// destination (and so which [`RenderStyle`]) it draws into.
+fn render_moon(ph: f64, date: &UTCDateTime, style: RenderStyle, palette: &Palette) -> String {
+    let is_apollo_11_day = is_apollo_11_day(date);
+
+    match style {
+        RenderStyle::Braille => {
+            let mut canvas = TextCanvas::new_auto().unwrap_or_default();
+            let offset_x = canvas.ucx() - (moon_icon::WIDTH / 2);
+            let offset_y = canvas.ucy() - (moon_icon::HEIGHT / 2);
+
+            if draw_moon_disk(&mut canvas, ph, offset_x, offset_y) && is_apollo_11_day {
+                draw_apollo_11_commemorative_dot(&mut canvas, offset_x, offset_y, palette);
+            }
+
+            canvas.to_string()
+        }
+        RenderStyle::Ascii | RenderStyle::HalfBlock => {
+            let canvas = TextCanvas::new_auto().unwrap_or_default();
+            let offset_x = canvas.ucx() - (moon_icon::WIDTH / 2);
+            let offset_y = canvas.ucy() - (moon_icon::HEIGHT / 2);
+
+            let mut coverage = DiskCoverage::new(canvas.ucx() * 2, canvas.ucy() * 2);
+
+            if draw_moon_disk(&mut coverage, ph, offset_x, offset_y) && is_apollo_11_day {
+                draw_apollo_11_commemorative_dot(&mut coverage, offset_x, offset_y, palette);
+            }
+
+            if style == RenderStyle::Ascii {
+                coverage.render_ascii()
+            } else {
+                coverage.render_half_block()
+            }
+        }
+    }
+}
+
+/// Whether, in local time if we're running in real time (otherwise UTC),
+/// `date` is July 20th — the day the Apollo 11 Commemorative Red Dot is
+/// shown at Tranquility Base instead of the regular mare floor.
+fn is_apollo_11_day(date: &UTCDateTime) -> bool {
+    let (month, day) = LocalDateTime::try_from(date).map_or_else(
+        |_| (date.month, date.day), // Fall back to UTC.
+        |local| (local.month, local.day),
+    );
+    month == 7 && day == 20
+}
+
+/// Draw the moon disk for phase `ph` onto `dest`, returning whether
+/// anything was drawn (the moon is left fully dark, and nothing is
+/// drawn, for a few hours around New Moon).
 #[allow(
     clippy::cast_possible_truncation,
     clippy::cast_precision_loss,
     clippy::cast_sign_loss,
     clippy::manual_range_contains
 )]
-fn render_moon(ph: f64, date: &UTCDateTime) -> String {
-    let mut canvas = TextCanvas::new_auto().unwrap_or_default();
-    let offset_x = canvas.ucx() - (moon_icon::WIDTH / 2);
-    let offset_y = canvas.ucy() - (moon_icon::HEIGHT / 2);
-
+fn draw_moon_disk<S: PixelSink>(dest: &mut S, ph: f64, offset_x: usize, offset_y: usize) -> bool {
     // Allow the moon to be completely dark for a few hours when new.
     if ph < 0.01 || ph > 0.99 {
-        return canvas.to_string();
+        return false;
     }
 
     // Fractional width of the visible portion.
@@ -354,7 +954,7 @@ fn render_moon(ph: f64, date: &UTCDateTime) -> String {
         // Bottom portion.
         blit_line(
             &moon_icon::MOON,
-            &mut canvas,
+            dest,
             lx,
             moon_icon::OFFSET + i,
             (rx - lx) + 1,
@@ -365,7 +965,7 @@ fn render_moon(ph: f64, date: &UTCDateTime) -> String {
         if i != 0 {
             blit_line(
                 &moon_icon::MOON,
-                &mut canvas,
+                dest,
                 lx,
                 moon_icon::OFFSET - i,
                 (rx - lx) + 1,
@@ -375,25 +975,13 @@ fn render_moon(ph: f64, date: &UTCDateTime) -> String {
         }
     }
 
-    // If it's July 20th (in local time if we're running in real time,
-    // otherwise based on UTC), display the Apollo 11 Commemorative
-    // Red Dot at Tranquility Base. Otherwise, just show the regular
-    // mare floor.
-    let (month, day) = LocalDateTime::try_from(date).map_or_else(
-        |_| (date.month, date.day), // Fall back to UTC.
-        |local| (local.month, local.day),
-    );
-    if month == 7 && day == 20 {
-        draw_apollo_11_commemorative_dot(&mut canvas, offset_x, offset_y);
-    }
-
-    canvas.to_string()
+    true
 }
 
 #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
-fn blit_line(
+fn blit_line<S: PixelSink>(
     source: &[u8; 4096],
-    dest: &mut TextCanvas,
+    dest: &mut S,
     x: usize,
     y: usize,
     width: usize,
@@ -411,8 +999,13 @@ fn blit_line(
 }
 
 #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
-fn draw_apollo_11_commemorative_dot(canvas: &mut TextCanvas, offset_x: usize, offset_y: usize) {
-    canvas.set_color(Color::new().bright_red());
+fn draw_apollo_11_commemorative_dot<S: PixelSink>(
+    canvas: &mut S,
+    offset_x: usize,
+    offset_y: usize,
+    palette: &Palette,
+) {
+    palette.highlight(canvas);
 
     let x = (moon_icon::APOLLO_11.0 + offset_x) as i32;
     let y = (moon_icon::APOLLO_11.1 + offset_y) as i32;
@@ -428,68 +1021,348 @@ fn draw_apollo_11_commemorative_dot(canvas: &mut TextCanvas, offset_x: usize, of
 }
 
 #[cfg(not(tarpaulin_include))]
-fn graph_moon_data(mcal: &MoonCalendar, verbose: bool) {
-    print!("{}", render_moon_graphs(mcal, verbose));
+fn graph_moon_data(mcal: &MoonCalendar, verbose: bool, style: RenderStyle, color: ColorMode) {
+    let palette = Palette::new(color.is_enabled());
+    print!("{}", render_moon_graphs(mcal, verbose, style, &palette));
 }
 
-fn render_moon_graphs(mcal: &MoonCalendar, verbose: bool) -> String {
-    let date = &mcal.utc_datetime;
-
-    let mut output = String::new();
+#[cfg(not(tarpaulin_include))]
+fn print_moon_data(mcal: &MoonCalendar, format: DataFormat) {
+    let series = pre_compute_yearly_graph_data(&mcal.utc_datetime);
+    match format {
+        DataFormat::Csv => print!("{}", yearly_data_series_to_csv(&series)),
+        DataFormat::Json => println!("{}", yearly_data_series_to_json(&series)),
+    }
+}
 
-    writeln!(output, "\n{}", graph_lunation_for_month(mcal)).unwrap();
+/// One row per [`YearlyMoonDataPoint`], matching the column order of its
+/// [`ToJSON`] fields.
+fn yearly_data_series_to_csv(series: &[YearlyMoonDataPoint]) -> String {
+    let mut csv = String::from(
+        "julian_date,utc_datetime,fraction_of_lunation,fraction_illuminated,\
+         distance_to_earth_km,subtends,ecliptic_longitude,ecliptic_latitude,parallax,\
+         sun_distance_to_earth_km,sun_subtends,sun_ecliptic_longitude\n",
+    );
+    for point in series {
+        writeln!(
+            csv,
+            "{},{},{},{},{},{},{},{},{},{},{},{}",
+            point.julian_date,
+            point.utc_datetime,
+            point.fraction_of_lunation,
+            point.fraction_illuminated,
+            point.distance_to_earth_km,
+            point.subtends,
+            point.ecliptic_longitude,
+            point.ecliptic_latitude,
+            point.parallax,
+            point.sun_distance_to_earth_km,
+            point.sun_subtends,
+            point.sun_ecliptic_longitude,
+        )
+        .unwrap();
+    }
+    csv
+}
 
-    let (x, y_phase) = pre_compute_yearly_graph_data(date);
+fn yearly_data_series_to_json(series: &[YearlyMoonDataPoint]) -> String {
+    format!(
+        "[{}]",
+        series
+            .iter()
+            .map(ToJSON::to_json)
+            .collect::<Vec<String>>()
+            .join(",")
+    )
+}
 
-    macro_rules! graph_data_for_year {
-        ($label:literal, $field:ident) => {
-            let y: Vec<f64> = y_phase.iter().map(|phase| phase.$field).collect();
-            let data = graph_data_for_year(&x, &y, date);
-            writeln!(output, "{} {}\n{data}", $label, date.year).unwrap();
-        };
-    }
+/// Expands the strftime-like `pattern` against `mphase`, copying
+/// everything else verbatim. Supported specifiers:
+///
+/// - `%Y`, `%m`, `%d`, `%H`, `%M`, `%S`: the evaluated datetime.
+/// - `%P`: phase name, e.g., "Waxing Gibbous".
+/// - `%p`: illuminated fraction, as a percentage (0–100).
+/// - `%a`: Moon age, in days since the last New Moon.
+/// - `%D`: distance to Earth, in kilometres.
+/// - `%%`: a literal `%`.
+///
+/// `%d` is kept for the day of month, matching `strftime`; distance is
+/// `%D` instead, to avoid that clash.
+///
+/// # Errors
+///
+/// Errors on a dangling `%`, or an unknown `%` specifier.
+fn format_moon_phase(pattern: &str, mphase: &MoonPhase) -> Result<String, String> {
+    let date = &mphase.utc_datetime;
+    let mut output = String::new();
+    let mut chars = pattern.chars();
 
-    graph_data_for_year!("Moon phases", fraction_illuminated);
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            output.push(c);
+            continue;
+        }
 
-    if verbose {
-        // Moon.
-        graph_data_for_year!("Moon distance to Earth", distance_to_earth_km);
-        graph_data_for_year!("Moon subtends", subtends);
-        graph_data_for_year!("Lunations", fraction_of_lunation);
-        graph_data_for_year!("Moon ecliptic longitude", ecliptic_longitude);
-        graph_data_for_year!("Moon ecliptic latitude", ecliptic_latitude);
-        graph_data_for_year!("Moon parallax", parallax);
+        let Some(specifier) = chars.next() else {
+            return Err(String::from("Dangling '%' at end of format pattern."));
+        };
 
-        // Sun.
-        graph_data_for_year!("Sun distance to Earth", sun_distance_to_earth_km);
-        graph_data_for_year!("Sun subtends", sun_subtends);
-        graph_data_for_year!("Sun ecliptic longitude", sun_ecliptic_longitude);
+        match specifier {
+            'Y' => write!(output, "{:04}", date.year).unwrap(),
+            'm' => write!(output, "{:02}", date.month).unwrap(),
+            'd' => write!(output, "{:02}", date.day).unwrap(),
+            'H' => write!(output, "{:02}", date.hour).unwrap(),
+            'M' => write!(output, "{:02}", date.minute).unwrap(),
+            'S' => write!(output, "{:02}", date.second).unwrap(),
+            'P' => output.push_str(&mphase.phase_name),
+            'p' => write!(output, "{:.2}", mphase.fraction_illuminated * 100.0).unwrap(),
+            'a' => write!(output, "{:.2}", mphase.age).unwrap(),
+            'D' => write!(output, "{:.0}", mphase.distance_to_earth_km).unwrap(),
+            '%' => output.push('%'),
+            _ => return Err(format!("Unknown format specifier '%{specifier}'.")),
+        }
     }
 
-    output
+    Ok(output)
 }
 
-fn graph_lunation_for_month(mcal: &MoonCalendar) -> String {
-    let f = |jd: f64| {
-        let phase = MoonPhase::for_julian_date(jd);
-        phase.fraction_illuminated
-    };
+#[cfg(not(tarpaulin_include))]
+fn print_principal_phases(datetime: &UTCDateTime) {
+    let pphases = PrincipalPhaseList::for_datetime(datetime);
+    println!("\n{pphases}\n");
+}
 
-    let mut canvas = TextCanvas::new(GRAPH_WIDTH, 13);
+#[cfg(not(tarpaulin_include))]
+fn print_lunar_apsides(datetime: &UTCDateTime) {
+    let apsides = LunarApsisList::for_datetime(datetime);
+    println!("\n{apsides}\n");
+}
 
-    let start = mcal.last_new_moon;
+#[cfg(not(tarpaulin_include))]
+fn print_calendar(datetime: &UTCDateTime) {
+    print!("{}", render_calendar(datetime));
+}
+
+#[cfg(not(tarpaulin_include))]
+fn print_chinese_lunar_date(datetime: &UTCDateTime) {
+    let chinese_date = ChineseLunarDate::for_datetime(datetime);
+    println!("\n{chinese_date}\n");
+}
+
+/// Render the month containing `datetime` as a weekday-aligned grid,
+/// with each day's dominant moon phase glyph (the same
+/// 🌑🌒🌓🌔🌕🌖🌗🌘 set used in [`graph_lunation_for_month`]).
+///
+/// Days on which a principal phase (New Moon, First Quarter, Full Moon,
+/// Last Quarter) actually occurs have their glyph wrapped in asterisks;
+/// `datetime`'s own day is wrapped in square brackets.
+///
+/// Ideally `datetime`'s day would be colour-highlighted using
+/// `TextCanvas`/`Color`, as those already handle colour elsewhere in
+/// this file (e.g. [`render_moon`]). They are built for pixel-addressed
+/// canvases though, not for tinting a single glyph inside a line of
+/// text, so square brackets are used here instead.
+fn render_calendar(datetime: &UTCDateTime) -> String {
+    let month_start = UTCDateTime::from_ymdhms(datetime.year, datetime.month, 1, 0, 0, 0);
+    let month_end = next_month_start(datetime.year, datetime.month);
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let days_in_month = (month_end.to_julian_date() - month_start.to_julian_date()) as u32;
+
+    let principal_phase_days: Vec<u32> = list_principal_phases_between(&month_start, &month_end)
+        .iter()
+        .map(|phase| phase.date_utc.day)
+        .collect();
+
+    let mut calendar = String::new();
+    writeln!(
+        calendar,
+        "{:04}-{:02}\n",
+        datetime.year, datetime.month
+    )
+    .unwrap();
+    writeln!(calendar, "Su Mo Tu We Th Fr Sa").unwrap();
+
+    for _ in 0..month_start.weekday {
+        write!(calendar, "     ").unwrap();
+    }
+
+    for day in 1..=days_in_month {
+        let noon = UTCDateTime::from_ymdhms(datetime.year, datetime.month, day, 12, 0, 0);
+        let icon = MoonPhase::for_datetime(&noon).phase_icon;
+
+        let glyph = if principal_phase_days.contains(&day) {
+            format!("*{icon}*")
+        } else {
+            format!(" {icon} ")
+        };
+
+        if day == datetime.day {
+            write!(calendar, "[{day:>2}]{glyph}").unwrap();
+        } else {
+            write!(calendar, " {day:>2} {glyph}").unwrap();
+        }
+
+        let weekday = (month_start.weekday + day - 1) % 7;
+        if weekday == 6 {
+            writeln!(calendar).unwrap();
+        }
+    }
+
+    calendar
+}
+
+#[cfg(not(tarpaulin_include))]
+fn print_ics(datetime: &UTCDateTime) {
+    print!("{}", render_ics(datetime));
+}
+
+/// Render the principal phases of the month containing `datetime` as a
+/// VCALENDAR stream of VEVENTs, so the output can be subscribed to by a
+/// calendar app.
+///
+/// Built on the same [`ical_event`]/[`ical_calendar`] primitives as
+/// [`YearlyMoonCalendar::to_ical`], rather than a separate exporter, so
+/// the CLI's `--ics` output stays RFC 5545 CRLF-compliant.
+fn render_ics(datetime: &UTCDateTime) -> String {
+    let start = UTCDateTime::from_ymdhms(datetime.year, datetime.month, 1, 0, 0, 0);
+    let end = next_month_start(datetime.year, datetime.month);
+    let phases = list_principal_phases_between(&start, &end);
+
+    let mut events = String::new();
+    for phase in &phases {
+        let summary = format!("{} {}", phase.icon, phase.name);
+        events.push_str(&ical_event(phase.date, &phase.date_utc, &summary));
+    }
+
+    ical_calendar(&events)
+}
+
+fn next_month_start(year: i32, month: u32) -> UTCDateTime {
+    if month == 12 {
+        UTCDateTime::from_ymdhms(year + 1, 1, 1, 0, 0, 0)
+    } else {
+        UTCDateTime::from_ymdhms(year, month + 1, 1, 0, 0, 0)
+    }
+}
+
+/// `style` only affects the moon icon (see [`render_moon`]); the phase
+/// and yearly graphs below are drawn via `textcanvas::charts::Plot`,
+/// which writes straight onto a braille [`TextCanvas`] with no way to
+/// read the result back into a [`DiskCoverage`], so they stay
+/// braille-only regardless of `style` for now.
+fn render_moon_graphs(
+    mcal: &MoonCalendar,
+    verbose: bool,
+    style: RenderStyle,
+    palette: &Palette,
+) -> String {
+    let date = &mcal.utc_datetime;
+
+    let mut output = String::new();
+
+    writeln!(
+        output,
+        "\n{}",
+        graph_lunation_for_month(mcal, style, palette)
+    )
+    .unwrap();
+
+    let series = pre_compute_yearly_graph_data(date);
+    let x: Vec<f64> = series.iter().map(|point| point.julian_date).collect();
+
+    macro_rules! graph_data_for_year {
+        ($label:literal, $field:ident) => {
+            let y: Vec<f64> = series.iter().map(|point| point.$field).collect();
+            let data = graph_data_for_year(&x, &y, date, style, palette);
+            writeln!(output, "{} {}\n{data}", $label, date.year).unwrap();
+        };
+    }
+
+    graph_data_for_year!("Moon phases", fraction_illuminated);
+
+    if verbose {
+        // Moon.
+        graph_data_for_year!("Moon distance to Earth", distance_to_earth_km);
+        graph_data_for_year!("Moon subtends", subtends);
+        graph_data_for_year!("Lunations", fraction_of_lunation);
+        graph_data_for_year!("Moon ecliptic longitude", ecliptic_longitude);
+        graph_data_for_year!("Moon ecliptic latitude", ecliptic_latitude);
+        graph_data_for_year!("Moon parallax", parallax);
+
+        // Sun.
+        graph_data_for_year!("Sun distance to Earth", sun_distance_to_earth_km);
+        graph_data_for_year!("Sun subtends", sun_subtends);
+        graph_data_for_year!("Sun ecliptic longitude", sun_ecliptic_longitude);
+    }
+
+    output
+}
+
+/// `_style` is accepted for symmetry with [`render_moon_graphs`], but
+/// unused for now (see that function's doc comment).
+fn graph_lunation_for_month(mcal: &MoonCalendar, _style: RenderStyle, palette: &Palette) -> String {
+    let f = |jd: f64| {
+        let phase = MoonPhase::for_julian_date(jd);
+        phase.fraction_illuminated
+    };
+
+    let mut canvas = TextCanvas::new(GRAPH_WIDTH, 13);
+
+    let start = mcal.last_new_moon;
     let end = mcal.next_new_moon;
     let (x, y) = Plot::compute_function(start, end, canvas.screen.fwidth(), &f);
 
     Plot::line(&mut canvas, &x, &y);
 
     let date = mcal.utc_datetime.to_julian_date();
-    canvas.set_color(Color::new().bright_red());
+    palette.highlight(&mut canvas);
     Plot::stroke_line_at_x(&mut canvas, date, &x);
 
     format!("{canvas}üåë      üåí         üåì         üåî         üåï        üåñ       üåó        üåò      üåë\n")
 }
 
+/// One sample of [`pre_compute_yearly_graph_data`]'s yearly series.
+///
+/// This is the exact intermediate the yearly graphs in
+/// [`render_moon_graphs`] are plotted from; [`print_moon_data`]
+/// serializes the same records, so the `--data` export is guaranteed to
+/// match what `--graph` draws.
+#[derive(Clone, Debug, PartialEq)]
+struct YearlyMoonDataPoint {
+    julian_date: f64,
+    utc_datetime: UTCDateTime,
+    fraction_of_lunation: f64,
+    fraction_illuminated: f64,
+    distance_to_earth_km: f64,
+    subtends: f64,
+    ecliptic_longitude: f64,
+    ecliptic_latitude: f64,
+    parallax: f64,
+    sun_distance_to_earth_km: f64,
+    sun_subtends: f64,
+    sun_ecliptic_longitude: f64,
+}
+
+impl ToJSON for YearlyMoonDataPoint {
+    fn to_json(&self) -> String {
+        format!(
+            r#"{{"julian_date":{},"utc_datetime":"{}","fraction_of_lunation":{},"fraction_illuminated":{},"distance_to_earth_km":{},"subtends":{},"ecliptic_longitude":{},"ecliptic_latitude":{},"parallax":{},"sun_distance_to_earth_km":{},"sun_subtends":{},"sun_ecliptic_longitude":{}}}"#,
+            self.julian_date,
+            self.utc_datetime,
+            self.fraction_of_lunation,
+            self.fraction_illuminated,
+            self.distance_to_earth_km,
+            self.subtends,
+            self.ecliptic_longitude,
+            self.ecliptic_latitude,
+            self.parallax,
+            self.sun_distance_to_earth_km,
+            self.sun_subtends,
+            self.sun_ecliptic_longitude,
+        )
+    }
+}
+
 /// Pre-compute all yearly values at once.
 ///
 /// This avoids lots of overhead, because otherwise we would need to
@@ -501,21 +1374,47 @@ fn graph_lunation_for_month(mcal: &MoonCalendar) -> String {
 ///
 /// With this method, we only compute 160 phases (once for each of the
 /// 160 pixels, as there are two horizontal pixels per output char).
-fn pre_compute_yearly_graph_data(date: &UTCDateTime) -> (Vec<f64>, Vec<MoonPhase>) {
+fn pre_compute_yearly_graph_data(date: &UTCDateTime) -> Vec<YearlyMoonDataPoint> {
     let f = |jd: f64| MoonPhase::for_julian_date(jd);
 
     let start = UTCDateTime::from_ymdhms(date.year, 1, 1, 0, 0, 0).to_julian_date();
     let end = UTCDateTime::from_ymdhms(date.year, 12, 31, 23, 59, 59).to_julian_date();
 
-    Plot::compute_function(start, end, f64::from(GRAPH_WIDTH * 2), &f)
+    let (x, y) = Plot::compute_function(start, end, f64::from(GRAPH_WIDTH * 2), &f);
+
+    x.into_iter()
+        .zip(y)
+        .map(|(julian_date, phase)| YearlyMoonDataPoint {
+            julian_date,
+            utc_datetime: phase.utc_datetime,
+            fraction_of_lunation: phase.fraction_of_lunation,
+            fraction_illuminated: phase.fraction_illuminated,
+            distance_to_earth_km: phase.distance_to_earth_km,
+            subtends: phase.subtends,
+            ecliptic_longitude: phase.ecliptic_longitude,
+            ecliptic_latitude: phase.ecliptic_latitude,
+            parallax: phase.parallax,
+            sun_distance_to_earth_km: phase.sun_distance_to_earth_km,
+            sun_subtends: phase.sun_subtends,
+            sun_ecliptic_longitude: phase.sun_ecliptic_longitude,
+        })
+        .collect()
 }
 
-fn graph_data_for_year(x: &[f64], y: &[f64], date: &UTCDateTime) -> String {
+/// `_style` is accepted for symmetry with [`render_moon_graphs`], but
+/// unused for now (see that function's doc comment).
+fn graph_data_for_year(
+    x: &[f64],
+    y: &[f64],
+    date: &UTCDateTime,
+    _style: RenderStyle,
+    palette: &Palette,
+) -> String {
     let mut canvas = TextCanvas::new(GRAPH_WIDTH, 4);
 
     Plot::line(&mut canvas, x, y);
 
-    canvas.set_color(Color::new().bright_red());
+    palette.highlight(&mut canvas);
     Plot::stroke_line_at_x(&mut canvas, date.to_julian_date(), x);
 
     format!("{canvas}")
@@ -527,6 +1426,9 @@ fn print_json(
     mcal: &MoonCalendar,
     ymcal: &Option<YearlyMoonCalendar>,
     scal: &Option<SunCalendar>,
+    nakshatra: &Option<(String, f64)>,
+    nearest_apsis: &Option<LunarApsis>,
+    chinese_calendar: &Option<ChineseLunarDate>,
 ) {
     let mphase = mphase.to_json();
     let mcal = mcal.to_json();
@@ -543,6 +1445,22 @@ fn print_json(
         print!(r#","sun_calendar":{scal}"#);
     }
 
+    if let Some((name, degrees)) = nakshatra {
+        print!(r#","nakshatra":{{"name":"{name}","degrees":{degrees}}}"#);
+    }
+
+    if let Some(apsis) = nearest_apsis {
+        print!(
+            r#","nearest_apsis":{{"name":"{}","date_utc":"{}","distance_to_earth_km":{}}}"#,
+            apsis.name, apsis.date_utc, apsis.distance_to_earth_km
+        );
+    }
+
+    if let Some(chinese_calendar) = chinese_calendar {
+        let chinese_calendar = chinese_calendar.to_json();
+        print!(r#","chinese_calendar":{chinese_calendar}"#);
+    }
+
     println!(r#"}}"#);
 }
 
@@ -552,8 +1470,27 @@ fn print_pretty(
     mcal: &MoonCalendar,
     ymcal: &Option<YearlyMoonCalendar>,
     scal: &Option<SunCalendar>,
+    nakshatra: &Option<(String, f64)>,
+    nearest_apsis: &Option<LunarApsis>,
+    chinese_calendar: &Option<ChineseLunarDate>,
 ) {
     println!("\n{mphase}\n");
+
+    if let Some((name, degrees)) = nakshatra {
+        println!("Nakshatra:\t\t{name} ({degrees:.2}°)\n");
+    }
+
+    if let Some(apsis) = nearest_apsis {
+        println!(
+            "Nearest {}:\t\t{}, {:.0} km\n",
+            apsis.name, apsis.date_utc, apsis.distance_to_earth_km
+        );
+    }
+
+    if let Some(chinese_calendar) = chinese_calendar {
+        println!("{chinese_calendar}\n");
+    }
+
     println!("{mcal}\n");
 
     if let Some(scal) = scal {
@@ -585,7 +1522,16 @@ mod tests {
                 verbose: false,
                 moon: false,
                 graph: false,
+                phases: false,
                 json: false,
+                ics: false,
+                apsides: false,
+                calendar: false,
+                chinese: false,
+                style: RenderStyle::Braille,
+                data: None,
+                color: ColorMode::Auto,
+                format: None,
             }
         );
     }
@@ -604,7 +1550,16 @@ mod tests {
                 verbose: false,
                 moon: false,
                 graph: false,
+                phases: false,
                 json: false,
+                ics: false,
+                apsides: false,
+                calendar: false,
+                chinese: false,
+                style: RenderStyle::Braille,
+                data: None,
+                color: ColorMode::Auto,
+                format: None,
             }
         );
     }
@@ -635,7 +1590,16 @@ mod tests {
         assert!(message.contains("-vv, --verbose"));
         assert!(message.contains("--moon"));
         assert!(message.contains("--graph"));
+        assert!(message.contains("--phases"));
         assert!(message.contains("--json"));
+        assert!(message.contains("--ics"));
+        assert!(message.contains("--apsides"));
+        assert!(message.contains("--calendar"));
+        assert!(message.contains("--chinese"));
+        assert!(message.contains("--style"));
+        assert!(message.contains("--data"));
+        assert!(message.contains("--color"));
+        assert!(message.contains("--format"));
         assert!(message.contains("[DATETIME]"));
         assert!(message.contains("[¬±TIMESTAMP]"));
     }
@@ -694,7 +1658,12 @@ mod tests {
         let mphase = MoonPhase::for_ymdhms(2024, 5, 17, 17, 48, 19);
 
         assert_eq!(
-            render_moon(mphase.fraction_of_lunation, &mphase.utc_datetime),
+            render_moon(
+            mphase.fraction_of_lunation,
+            &mphase.utc_datetime,
+            RenderStyle::Braille,
+            &Palette::new(true),
+        ),
             "\
 ‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä
 ‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä
@@ -729,7 +1698,12 @@ mod tests {
         let mphase = MoonPhase::for_ymdhms(2024, 5, 29, 17, 48, 19);
 
         assert_eq!(
-            render_moon(mphase.fraction_of_lunation, &mphase.utc_datetime),
+            render_moon(
+            mphase.fraction_of_lunation,
+            &mphase.utc_datetime,
+            RenderStyle::Braille,
+            &Palette::new(true),
+        ),
             "\
 ‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä
 ‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä‚†Ä
@@ -763,7 +1737,12 @@ mod tests {
     fn moon_new() {
         let mphase = MoonPhase::for_ymdhms(2024, 6, 6, 17, 5, 0);
 
-        let render = render_moon(mphase.fraction_of_lunation, &mphase.utc_datetime);
+        let render = render_moon(
+            mphase.fraction_of_lunation,
+            &mphase.utc_datetime,
+            RenderStyle::Braille,
+            &Palette::new(true),
+        );
 
         assert!(render.trim_matches(&['\n', '‚†Ä']).is_empty());
     }
@@ -772,11 +1751,30 @@ mod tests {
     fn moon_apollo_11() {
         let mphase = MoonPhase::for_ymdhms(1969, 7, 20, 20, 17, 40);
 
-        let render = render_moon(mphase.fraction_of_lunation, &mphase.utc_datetime);
+        let render = render_moon(
+            mphase.fraction_of_lunation,
+            &mphase.utc_datetime,
+            RenderStyle::Braille,
+            &Palette::new(true),
+        );
 
         assert!(render.contains("\x1b[0;91m‚†õ\x1b[0m"));
     }
 
+    #[test]
+    fn moon_apollo_11_without_color_has_no_escape_sequence() {
+        let mphase = MoonPhase::for_ymdhms(1969, 7, 20, 20, 17, 40);
+
+        let render = render_moon(
+            mphase.fraction_of_lunation,
+            &mphase.utc_datetime,
+            RenderStyle::Braille,
+            &Palette::new(false),
+        );
+
+        assert!(!render.contains('\x1b'));
+    }
+
     #[test]
     fn graph() {
         let args = vec![String::new(), String::from("--graph")].into_iter();
@@ -813,11 +1811,437 @@ mod tests {
         assert!(config.datetime.is_some());
     }
 
+    #[test]
+    fn phases() {
+        let args = vec![String::new(), String::from("--phases")].into_iter();
+        let config = Config::new(args).unwrap();
+
+        assert!(config.phases);
+    }
+
+    #[test]
+    fn ics() {
+        let args = vec![String::new(), String::from("--ics")].into_iter();
+        let config = Config::new(args).unwrap();
+
+        assert!(config.ics);
+    }
+
+    #[test]
+    fn apsides() {
+        let args = vec![String::new(), String::from("--apsides")].into_iter();
+        let config = Config::new(args).unwrap();
+
+        assert!(config.apsides);
+    }
+
+    #[test]
+    fn calendar() {
+        let args = vec![String::new(), String::from("--calendar")].into_iter();
+        let config = Config::new(args).unwrap();
+
+        assert!(config.calendar);
+    }
+
+    #[test]
+    fn render_calendar_contains_one_glyph_per_day_and_marks_target_day() {
+        let datetime = UTCDateTime::from_ymdhms(2024, 5, 15, 0, 0, 0);
+        let calendar = render_calendar(&datetime);
+
+        assert!(calendar.starts_with("2024-05"));
+        assert!(calendar.contains("[15]"));
+
+        // May 2024 has 31 days; every day from 1 to 31 is rendered
+        // somewhere in the grid, either plain (" N ") or as the target
+        // day ("[N]").
+        for day in 1..=31 {
+            assert!(
+                calendar.contains(&format!(" {day:>2} ")) || calendar.contains(&format!("[{day}]")),
+                "day {day} not found in calendar"
+            );
+        }
+    }
+
+    #[test]
+    fn chinese() {
+        let args = vec![String::new(), String::from("--chinese")].into_iter();
+        let config = Config::new(args).unwrap();
+
+        assert!(config.chinese);
+    }
+
+    #[test]
+    fn style_defaults_to_braille() {
+        let args = vec![String::new()].into_iter();
+        let config = Config::new(args).unwrap();
+
+        assert_eq!(config.style, RenderStyle::Braille);
+    }
+
+    #[test]
+    fn style_ascii() {
+        let args = vec![
+            String::new(),
+            String::from("--style"),
+            String::from("ascii"),
+        ]
+        .into_iter();
+        let config = Config::new(args).unwrap();
+
+        assert_eq!(config.style, RenderStyle::Ascii);
+    }
+
+    #[test]
+    fn style_halfblock() {
+        let args = vec![
+            String::new(),
+            String::from("--style"),
+            String::from("halfblock"),
+        ]
+        .into_iter();
+        let config = Config::new(args).unwrap();
+
+        assert_eq!(config.style, RenderStyle::HalfBlock);
+    }
+
+    #[test]
+    fn style_missing_value_is_an_error() {
+        let args = vec![String::new(), String::from("--style")].into_iter();
+
+        assert!(Config::new(args).is_err());
+    }
+
+    #[test]
+    fn style_unknown_value_is_an_error() {
+        let args = vec![
+            String::new(),
+            String::from("--style"),
+            String::from("crayon"),
+        ]
+        .into_iter();
+
+        assert!(Config::new(args).is_err());
+    }
+
+    #[test]
+    fn data_defaults_to_none() {
+        let args = vec![String::new()].into_iter();
+        let config = Config::new(args).unwrap();
+
+        assert_eq!(config.data, None);
+    }
+
+    #[test]
+    fn data_csv() {
+        let args = vec![
+            String::new(),
+            String::from("--data"),
+            String::from("csv"),
+        ]
+        .into_iter();
+        let config = Config::new(args).unwrap();
+
+        assert_eq!(config.data, Some(DataFormat::Csv));
+    }
+
+    #[test]
+    fn data_json() {
+        let args = vec![
+            String::new(),
+            String::from("--data"),
+            String::from("json"),
+        ]
+        .into_iter();
+        let config = Config::new(args).unwrap();
+
+        assert_eq!(config.data, Some(DataFormat::Json));
+    }
+
+    #[test]
+    fn data_missing_value_is_an_error() {
+        let args = vec![String::new(), String::from("--data")].into_iter();
+
+        assert!(Config::new(args).is_err());
+    }
+
+    #[test]
+    fn data_unknown_value_is_an_error() {
+        let args = vec![
+            String::new(),
+            String::from("--data"),
+            String::from("xml"),
+        ]
+        .into_iter();
+
+        assert!(Config::new(args).is_err());
+    }
+
+    #[test]
+    fn color_defaults_to_auto() {
+        let args = vec![String::new()].into_iter();
+        let config = Config::new(args).unwrap();
+
+        assert_eq!(config.color, ColorMode::Auto);
+    }
+
+    #[test]
+    fn color_always() {
+        let args = vec![
+            String::new(),
+            String::from("--color"),
+            String::from("always"),
+        ]
+        .into_iter();
+        let config = Config::new(args).unwrap();
+
+        assert_eq!(config.color, ColorMode::Always);
+    }
+
+    #[test]
+    fn color_never() {
+        let args = vec![
+            String::new(),
+            String::from("--color"),
+            String::from("never"),
+        ]
+        .into_iter();
+        let config = Config::new(args).unwrap();
+
+        assert_eq!(config.color, ColorMode::Never);
+    }
+
+    #[test]
+    fn color_missing_value_is_an_error() {
+        let args = vec![String::new(), String::from("--color")].into_iter();
+
+        assert!(Config::new(args).is_err());
+    }
+
+    #[test]
+    fn color_unknown_value_is_an_error() {
+        let args = vec![
+            String::new(),
+            String::from("--color"),
+            String::from("rainbow"),
+        ]
+        .into_iter();
+
+        assert!(Config::new(args).is_err());
+    }
+
+    #[test]
+    fn format_defaults_to_none() {
+        let args = vec![String::new()].into_iter();
+        let config = Config::new(args).unwrap();
+
+        assert_eq!(config.format, None);
+    }
+
+    #[test]
+    fn format_pattern() {
+        let args = vec![
+            String::new(),
+            String::from("--format"),
+            String::from("%Y-%m-%d"),
+        ]
+        .into_iter();
+        let config = Config::new(args).unwrap();
+
+        assert_eq!(config.format, Some(String::from("%Y-%m-%d")));
+    }
+
+    #[test]
+    fn format_missing_value_is_an_error() {
+        let args = vec![String::new(), String::from("--format")].into_iter();
+
+        assert!(Config::new(args).is_err());
+    }
+
+    #[test]
+    fn palette_highlight_is_a_no_op_when_disabled() {
+        let mut coverage = DiskCoverage::new(4, 4);
+        let palette = Palette::new(false);
+
+        // `DiskCoverage`'s `set_color` is already a no-op, so this only
+        // checks that `highlight` doesn't panic when disabled and
+        // doesn't touch any pixels on its own.
+        palette.highlight(&mut coverage);
+
+        assert!(!(0..4).any(|x| (0..4).any(|y| coverage.is_set(x, y))));
+    }
+
+    #[test]
+    fn yearly_data_series_matches_graph_sample_count() {
+        let mcal = MoonCalendar::for_julian_date(2_460_472.289_13);
+        let series = pre_compute_yearly_graph_data(&mcal.utc_datetime);
+
+        assert_eq!(series.len(), (GRAPH_WIDTH * 2) as usize);
+        assert!(series
+            .windows(2)
+            .all(|w| w[0].julian_date < w[1].julian_date));
+    }
+
+    #[test]
+    fn yearly_data_series_to_csv_has_a_header_and_one_row_per_sample() {
+        let mcal = MoonCalendar::for_julian_date(2_460_472.289_13);
+        let series = pre_compute_yearly_graph_data(&mcal.utc_datetime);
+
+        let csv = yearly_data_series_to_csv(&series);
+        let mut lines = csv.lines();
+
+        assert_eq!(
+            lines.next().unwrap(),
+            "julian_date,utc_datetime,fraction_of_lunation,fraction_illuminated,\
+             distance_to_earth_km,subtends,ecliptic_longitude,ecliptic_latitude,parallax,\
+             sun_distance_to_earth_km,sun_subtends,sun_ecliptic_longitude"
+        );
+        assert_eq!(lines.count(), series.len());
+    }
+
+    #[test]
+    fn yearly_data_series_to_json_is_an_array_of_one_object_per_sample() {
+        let mcal = MoonCalendar::for_julian_date(2_460_472.289_13);
+        let series = pre_compute_yearly_graph_data(&mcal.utc_datetime);
+
+        let json = yearly_data_series_to_json(&series);
+
+        assert!(json.starts_with('['));
+        assert!(json.ends_with(']'));
+        assert_eq!(json.matches(r#""julian_date":"#).count(), series.len());
+    }
+
+    #[test]
+    fn format_moon_phase_datetime_specifiers() {
+        let mphase = MoonPhase::for_ymdhms(1969, 7, 20, 20, 17, 40);
+
+        let formatted = format_moon_phase("%Y-%m-%d %H:%M:%S", &mphase).unwrap();
+
+        assert_eq!(formatted, "1969-07-20 20:17:40");
+    }
+
+    #[test]
+    fn format_moon_phase_moon_specifiers() {
+        let mphase = MoonPhase::for_ymdhms(1969, 7, 20, 20, 17, 40);
+
+        let formatted = format_moon_phase("%P|%p|%a|%D", &mphase).unwrap();
+
+        assert_eq!(
+            formatted,
+            format!(
+                "{}|{:.2}|{:.2}|{:.0}",
+                mphase.phase_name,
+                mphase.fraction_illuminated * 100.0,
+                mphase.age,
+                mphase.distance_to_earth_km
+            )
+        );
+    }
+
+    #[test]
+    fn format_moon_phase_literal_percent() {
+        let mphase = MoonPhase::for_ymdhms(1969, 7, 20, 20, 17, 40);
+
+        let formatted = format_moon_phase("100%%", &mphase).unwrap();
+
+        assert_eq!(formatted, "100%");
+    }
+
+    #[test]
+    fn format_moon_phase_literal_text_is_kept_verbatim() {
+        let mphase = MoonPhase::for_ymdhms(1969, 7, 20, 20, 17, 40);
+
+        let formatted = format_moon_phase("Moon phase: %P", &mphase).unwrap();
+
+        assert_eq!(formatted, format!("Moon phase: {}", mphase.phase_name));
+    }
+
+    #[test]
+    fn format_moon_phase_unknown_specifier_is_an_error() {
+        let mphase = MoonPhase::for_ymdhms(1969, 7, 20, 20, 17, 40);
+
+        assert!(format_moon_phase("%Q", &mphase).is_err());
+    }
+
+    #[test]
+    fn format_moon_phase_dangling_percent_is_an_error() {
+        let mphase = MoonPhase::for_ymdhms(1969, 7, 20, 20, 17, 40);
+
+        assert!(format_moon_phase("100%", &mphase).is_err());
+    }
+
+    #[test]
+    fn render_moon_ascii_is_made_of_density_ramp_characters() {
+        let mphase = MoonPhase::for_ymdhms(2024, 5, 17, 17, 48, 19);
+
+        let render = render_moon(
+            mphase.fraction_of_lunation,
+            &mphase.utc_datetime,
+            RenderStyle::Ascii,
+            &Palette::new(true),
+        );
+
+        assert!(!render.is_empty());
+        assert!(render
+            .chars()
+            .all(|c| " .:-=+*#%@\n".contains(c)));
+    }
+
+    #[test]
+    fn render_moon_half_block_is_made_of_block_characters() {
+        let mphase = MoonPhase::for_ymdhms(2024, 5, 17, 17, 48, 19);
+
+        let render = render_moon(
+            mphase.fraction_of_lunation,
+            &mphase.utc_datetime,
+            RenderStyle::HalfBlock,
+            &Palette::new(true),
+        );
+
+        assert!(!render.is_empty());
+        assert!(render.chars().all(|c| " ▀▄█\n".contains(c)));
+    }
+
+    #[test]
+    fn render_moon_ascii_and_half_block_are_dark_around_new_moon() {
+        let mphase = MoonPhase::for_ymdhms(2024, 6, 6, 17, 5, 0);
+
+        let ascii = render_moon(
+            mphase.fraction_of_lunation,
+            &mphase.utc_datetime,
+            RenderStyle::Ascii,
+            &Palette::new(true),
+        );
+        let half_block = render_moon(
+            mphase.fraction_of_lunation,
+            &mphase.utc_datetime,
+            RenderStyle::HalfBlock,
+            &Palette::new(true),
+        );
+
+        assert!(ascii.trim_matches(&[' ', '\n']).is_empty());
+        assert!(half_block.trim_matches(&[' ', '\n']).is_empty());
+    }
+
+    #[test]
+    fn render_ics_contains_a_vevent_per_principal_phase() {
+        let datetime = UTCDateTime::from_ymdhms(2024, 5, 15, 0, 0, 0);
+        let ics = render_ics(&datetime);
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.trim_end().ends_with("END:VCALENDAR"));
+        assert!(ics.contains("BEGIN:VEVENT\r\n"));
+        assert!(ics.contains("SUMMARY:"));
+        assert_eq!(
+            ics.matches("BEGIN:VEVENT").count(),
+            ics.matches("END:VEVENT").count()
+        );
+    }
+
     #[test]
     fn graph_regular() {
         let mcal = MoonCalendar::for_julian_date(2_460_472.289_13);
 
-        let render = render_moon_graphs(&mcal, false);
+        let render = render_moon_graphs(&mcal, false, RenderStyle::Braille, &Palette::new(true));
 
         assert!(render.contains("\x1b[0;91m"));
         assert!(render.contains("\x1b[0m"));
@@ -852,11 +2276,20 @@ Moon phases 2024
         );
     }
 
+    #[test]
+    fn graph_regular_without_color_has_no_escape_sequence() {
+        let mcal = MoonCalendar::for_julian_date(2_460_472.289_13);
+
+        let render = render_moon_graphs(&mcal, false, RenderStyle::Braille, &Palette::new(false));
+
+        assert!(!render.contains('\x1b'));
+    }
+
     #[test]
     fn graph_verbose() {
         let mcal = MoonCalendar::for_julian_date(2_460_472.289_13);
 
-        let render = render_moon_graphs(&mcal, true);
+        let render = render_moon_graphs(&mcal, true, RenderStyle::Braille, &Palette::new(true));
 
         assert!(render.contains("\x1b[0;91m"));
         assert!(render.contains("\x1b[0m"));
@@ -1007,6 +2440,32 @@ Sun ecliptic longitude 2024
         assert_eq!(config.datetime, Some(String::from("-1715791943")));
     }
 
+    #[test]
+    fn gmonthday() {
+        // Because it could be mistaken for an unknown `--` flag.
+        let args = vec![String::new(), String::from("--05-15")].into_iter();
+        let config = Config::new(args).unwrap();
+
+        assert_eq!(config.datetime, Some(String::from("--05-15")));
+    }
+
+    #[test]
+    fn gmonth() {
+        let args = vec![String::new(), String::from("--05")].into_iter();
+        let config = Config::new(args).unwrap();
+
+        assert_eq!(config.datetime, Some(String::from("--05")));
+    }
+
+    #[test]
+    fn gday() {
+        // Because it could be mistaken for an unknown `--` flag.
+        let args = vec![String::new(), String::from("---15")].into_iter();
+        let config = Config::new(args).unwrap();
+
+        assert_eq!(config.datetime, Some(String::from("---15")));
+    }
+
     #[test]
     fn error_invalid_argument_full() {
         let args = vec![String::new(), String::from("--invalid")].into_iter();
@@ -1027,30 +2486,176 @@ Sun ecliptic longitude 2024
 
     // Main.
 
+    fn test_now() -> UTCDateTime {
+        UTCDateTime::from_ymdhms(2024, 6, 11, 16, 43, 2)
+    }
+
     #[test]
     fn try_parse_datetime_timestamp() {
-        let dt = try_parse_datetime("966600000").unwrap();
+        let dt = try_parse_datetime("966600000", &test_now()).unwrap();
 
         assert_eq!(dt, UTCDateTime::from_ymdhms(2000, 8, 18, 12, 0, 0));
     }
 
     #[test]
     fn try_parse_datetime_julian_date() {
-        let dt = try_parse_datetime("2460473.19655").unwrap();
+        let dt = try_parse_datetime("2460473.19655", &test_now()).unwrap();
 
         assert_eq!(dt, UTCDateTime::from_ymdhms(2024, 6, 11, 16, 43, 2));
     }
 
     #[test]
     fn try_parse_datetime_datetime() {
-        let dt = try_parse_datetime("1964-12-20T04:35:00Z").unwrap();
+        let dt = try_parse_datetime("1964-12-20T04:35:00Z", &test_now()).unwrap();
 
         assert_eq!(dt, UTCDateTime::from_ymdhms(1964, 12, 20, 4, 35, 0));
     }
 
+    #[test]
+    fn try_parse_datetime_rfc2822() {
+        let dt = try_parse_datetime("Sun, 20 Jul 1969 20:17:40 +0000", &test_now()).unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(1969, 7, 20, 20, 17, 40));
+    }
+
+    #[test]
+    fn try_parse_datetime_http_date() {
+        let dt = try_parse_datetime("Sun, 20 Jul 1969 20:17:40 GMT", &test_now()).unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(1969, 7, 20, 20, 17, 40));
+    }
+
+    #[test]
+    fn try_parse_datetime_gyear_takes_precedence_over_timestamp() {
+        // A bare 4-digit number is a gYear, not a Unix timestamp.
+        let dt = try_parse_datetime("2024", &test_now()).unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(2024, 1, 1, 0, 0, 0));
+    }
+
+    #[test]
+    fn try_parse_datetime_gyearmonth() {
+        let dt = try_parse_datetime("2024-05", &test_now()).unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(2024, 5, 1, 0, 0, 0));
+    }
+
+    #[test]
+    fn try_parse_datetime_gmonthday() {
+        let dt = try_parse_datetime("--05-15", &test_now()).unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(2024, 5, 15, 0, 0, 0));
+    }
+
+    #[test]
+    fn try_parse_datetime_gmonth() {
+        let dt = try_parse_datetime("--05", &test_now()).unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(2024, 5, 1, 0, 0, 0));
+    }
+
+    #[test]
+    fn try_parse_datetime_gday() {
+        let dt = try_parse_datetime("---15", &test_now()).unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(2024, 6, 15, 0, 0, 0));
+    }
+
+    #[test]
+    fn try_parse_datetime_gyearmonth_out_of_range_month_is_none() {
+        let dt = try_parse_datetime("2024-13", &test_now());
+
+        assert!(dt.is_none());
+    }
+
+    #[test]
+    fn try_parse_datetime_gmonthday_out_of_range_day_is_none() {
+        // April only has 30 days.
+        let dt = try_parse_datetime("--04-31", &test_now());
+
+        assert!(dt.is_none());
+    }
+
+    #[test]
+    fn try_parse_datetime_gmonthday_leap_year_aware() {
+        let dt = try_parse_datetime("--02-29", &UTCDateTime::from_ymdhms(2024, 1, 1, 0, 0, 0))
+            .unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(2024, 2, 29, 0, 0, 0));
+
+        let dt = try_parse_datetime("--02-29", &UTCDateTime::from_ymdhms(2023, 1, 1, 0, 0, 0));
+
+        assert!(dt.is_none());
+    }
+
+    #[test]
+    fn try_parse_datetime_two_digit_year_dash_separator() {
+        let dt = try_parse_datetime("97-11-21", &test_now()).unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(1997, 11, 21, 0, 0, 0));
+    }
+
+    #[test]
+    fn try_parse_datetime_two_digit_year_slash_separator() {
+        let dt = try_parse_datetime("15/02/18", &test_now()).unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(2015, 2, 18, 0, 0, 0));
+    }
+
     #[test]
     fn try_parse_datetime_error() {
-        let dt = try_parse_datetime("invalid");
+        let dt = try_parse_datetime("invalid", &test_now());
+
+        assert!(dt.is_none());
+    }
+
+    #[test]
+    fn resolve_two_digit_year_within_window_same_century() {
+        // now = 2024, "24" is this year.
+        assert_eq!(resolve_two_digit_year(24, 2024), 2024);
+    }
+
+    #[test]
+    fn resolve_two_digit_year_just_within_future_pivot() {
+        // now = 2024, pivot allows up to 2044.
+        assert_eq!(resolve_two_digit_year(44, 2024), 2044);
+    }
+
+    #[test]
+    fn resolve_two_digit_year_beyond_future_pivot_rolls_back_a_century() {
+        // now = 2024, "45" would be 2045 (21 years ahead), so it rolls
+        // back to 1945.
+        assert_eq!(resolve_two_digit_year(45, 2024), 1945);
+    }
+
+    #[test]
+    fn resolve_two_digit_year_just_within_past_pivot() {
+        // now = 2024, pivot allows back to 1945 (79 years behind).
+        assert_eq!(resolve_two_digit_year(45, 2024), 1945);
+    }
+
+    #[test]
+    fn resolve_two_digit_year_beyond_past_pivot_rolls_forward_a_century() {
+        // now = 2099, "00" interpreted in 2000 would be 99 years behind
+        // (> 79), so it rolls forward to 2100.
+        assert_eq!(resolve_two_digit_year(0, 2099), 2100);
+    }
+
+    #[test]
+    fn try_from_two_digit_year_string_requires_separator() {
+        let now = UTCDateTime::from_ymdhms(2024, 1, 1, 0, 0, 0);
+
+        // Ambiguous with a Unix timestamp; must go to `try_from_timestamp`.
+        let dt = try_from_two_digit_year_string("971121", &now);
+
+        assert!(dt.is_none());
+    }
+
+    #[test]
+    fn try_from_two_digit_year_string_rejects_four_digit_year() {
+        let now = UTCDateTime::from_ymdhms(2024, 1, 1, 0, 0, 0);
+
+        let dt = try_from_two_digit_year_string("2024-11-21", &now);
 
         assert!(dt.is_none());
     }
@@ -23,10 +23,12 @@ fn timestamp_to_utcdatetime(timestamp: i64) -> Result<UTCDateTime, &'static str>
 }
 
 fn iso_datetime_string_to_utcdatetime(iso_datetime: &str) -> Result<UTCDateTime, &'static str> {
+    let iso_datetime = normalize_leap_second(iso_datetime);
+
     let datetime = if iso_datetime.contains('T') || iso_datetime.contains('t') {
-        parse_datetime(iso_datetime)
+        parse_datetime(&iso_datetime)
     } else {
-        parse_date(iso_datetime)
+        parse_date(&iso_datetime)
     };
 
     let Ok(datetime) = datetime else {
@@ -38,10 +40,49 @@ fn iso_datetime_string_to_utcdatetime(iso_datetime: &str) -> Result<UTCDateTime,
     Ok(UTCDateTime::from(datetime))
 }
 
+/// Folds a `:60` leap second into `:59` of the same minute—the crate has
+/// no sub-minute astronomical need, so treating the leap second as the
+/// last instant of the minute is an acceptable normalization. Only
+/// touches the time component, so the date and the `Z`/numeric offset
+/// suffix are left untouched.
+///
+/// Any fractional-second digits after the `.` are left in place: `time`'s
+/// RFC 3339 parser already understands those natively, and they end up
+/// in [`UTCDateTime::nanosecond`].
+fn normalize_leap_second(datetime: &str) -> String {
+    let Some(t_pos) = datetime.find(['T', 't']) else {
+        return datetime.to_owned();
+    };
+
+    let (date_part, time_part) = datetime.split_at(t_pos + 1);
+
+    let offset_start = time_part.find(['Z', 'z', '+', '-']);
+    let (clock, suffix) = match offset_start {
+        Some(i) => time_part.split_at(i),
+        None => (time_part, ""),
+    };
+
+    let clock = match clock.find(":60") {
+        Some(i) if clock[i + 3..].starts_with('.') || clock[i + 3..].is_empty() => {
+            format!("{}:59{}", &clock[..i], &clock[i + 3..])
+        }
+        _ => clock.to_owned(),
+    };
+
+    format!("{date_part}{clock}{suffix}")
+}
+
 fn parse_datetime(datetime: &str) -> Result<time::OffsetDateTime, &'static str> {
     let mut datetime = datetime.to_owned();
-    // Implicit UTC if no offset provided.
-    if !datetime.ends_with('Z') && !datetime.ends_with('z') && !datetime.contains('+') {
+    // Implicit UTC if no offset provided. Only look for the offset sign
+    // in the time portion (after 'T'/'t'), like `normalize_leap_second`
+    // does, so the date separators' '-' aren't mistaken for a negative
+    // offset.
+    let has_offset = datetime
+        .find(['T', 't'])
+        .map(|t_pos| &datetime[t_pos + 1..])
+        .is_some_and(|time_part| time_part.contains(['Z', 'z', '+', '-']));
+    if !has_offset {
         datetime.push('Z');
     }
     let format = time::format_description::well_known::Rfc3339;
@@ -56,6 +97,425 @@ fn parse_date(date: &str) -> Result<time::OffsetDateTime, &'static str> {
     Ok(time::OffsetDateTime::new_utc(date, time::Time::MIDNIGHT))
 }
 
+/// RFC 2822 (e.g., email `Date:` headers), numeric offset only
+/// (`+0000`, not the obsolete `GMT`/`UT` zone names—see
+/// [`http_date_string_to_utcdatetime`] for HTTP-dates ending in `GMT`,
+/// and [`rfc2822_string_to_utcdatetime_tolerant`] for the obsolete zones).
+fn rfc2822_string_to_utcdatetime(rfc2822: &str) -> Result<UTCDateTime, &'static str> {
+    let format = time::format_description::well_known::Rfc2822;
+    let Ok(datetime) = time::OffsetDateTime::parse(rfc2822, &format) else {
+        return Err("Invalid RFC 2822 datetime string.");
+    };
+    let datetime = datetime.to_offset(time::UtcOffset::UTC);
+    Ok(UTCDateTime::from(datetime))
+}
+
+/// HTTP-date, i.e., the preferred `IMF-fixdate` format from RFC 7231
+/// §7.1.1.1 (e.g., the `Date:`/`Last-Modified:` HTTP headers). The two
+/// obsolete HTTP-date formats (RFC 850 and asctime) are not supported by
+/// this function specifically — see [`httpdate_string_to_utcdatetime`]
+/// for a parser that accepts all three.
+fn http_date_string_to_utcdatetime(http_date: &str) -> Result<UTCDateTime, &'static str> {
+    let format = time::format_description::parse(
+        "[weekday repr:short], [day] [month repr:short] [year] [hour]:[minute]:[second] GMT",
+    )
+    .unwrap();
+    let Ok(datetime) = time::PrimitiveDateTime::parse(http_date, &format) else {
+        return Err("Invalid HTTP-date string.");
+    };
+    Ok(UTCDateTime::from(datetime.assume_utc()))
+}
+
+/// Tolerant RFC 2822 parser, unlike [`rfc2822_string_to_utcdatetime`]'s
+/// strict `time`-rs well-known format: accepts an optional leading
+/// day-of-week, and the obsolete `UT`/`GMT` and North American zone
+/// abbreviations (`EST` through `PDT`) in addition to numeric `±HHMM`
+/// offsets. Single-letter military zones are not recognized.
+fn rfc2822_string_to_utcdatetime_tolerant(rfc2822: &str) -> Result<UTCDateTime, &'static str> {
+    if rfc2822.contains('(') || rfc2822.contains(')') {
+        return Err("Parenthetical comments are not supported.");
+    }
+
+    // Drop the optional leading "Wed, " day-of-week; it's not verified
+    // against the parsed date.
+    let rfc2822 = rfc2822.split_once(',').map_or(rfc2822, |(_, rest)| rest);
+
+    let mut tokens = rfc2822.split_whitespace();
+    let (Some(day), Some(month), Some(year), Some(time), Some(zone)) = (
+        tokens.next(),
+        tokens.next(),
+        tokens.next(),
+        tokens.next(),
+        tokens.next(),
+    ) else {
+        return Err("Invalid RFC 2822 datetime string.");
+    };
+    if tokens.next().is_some() {
+        return Err("Invalid RFC 2822 datetime string.");
+    }
+
+    let Ok(day) = day.parse::<u8>() else {
+        return Err("Invalid day.");
+    };
+    let Ok(month) = rfc2822_monthname_to_month(month) else {
+        return Err("Invalid month.");
+    };
+    let Ok(year) = year.parse::<i32>() else {
+        return Err("Invalid year.");
+    };
+    let Ok(date) = time::Date::from_calendar_date(year, month, day) else {
+        return Err("Invalid date.");
+    };
+
+    let mut clock = time.splitn(3, ':');
+    let (Some(hour), Some(minute)) = (clock.next(), clock.next()) else {
+        return Err("Invalid time.");
+    };
+    let second = clock.next().unwrap_or("0");
+    let (Ok(hour), Ok(minute), Ok(second)) =
+        (hour.parse::<u8>(), minute.parse::<u8>(), second.parse::<u8>())
+    else {
+        return Err("Invalid time.");
+    };
+    let Ok(time) = time::Time::from_hms(hour, minute, second) else {
+        return Err("Invalid time.");
+    };
+
+    let offset_seconds = rfc2822_zone_to_offset_seconds(zone)?;
+    let Ok(offset) = time::UtcOffset::from_whole_seconds(offset_seconds) else {
+        return Err("Offset is out of range.");
+    };
+
+    let datetime = time::OffsetDateTime::new_in_offset(date, time, offset);
+    Ok(UTCDateTime::from(datetime.to_offset(time::UtcOffset::UTC)))
+}
+
+fn rfc2822_monthname_to_month(month: &str) -> Result<time::Month, &'static str> {
+    match month {
+        "Jan" => Ok(time::Month::January),
+        "Feb" => Ok(time::Month::February),
+        "Mar" => Ok(time::Month::March),
+        "Apr" => Ok(time::Month::April),
+        "May" => Ok(time::Month::May),
+        "Jun" => Ok(time::Month::June),
+        "Jul" => Ok(time::Month::July),
+        "Aug" => Ok(time::Month::August),
+        "Sep" => Ok(time::Month::September),
+        "Oct" => Ok(time::Month::October),
+        "Nov" => Ok(time::Month::November),
+        "Dec" => Ok(time::Month::December),
+        _ => Err("Invalid month."),
+    }
+}
+
+/// Numeric `±HHMM` offsets, plus the obsolete zone abbreviations still
+/// seen in the wild (RFC 2822 §4.3).
+fn rfc2822_zone_to_offset_seconds(zone: &str) -> Result<i32, &'static str> {
+    match zone {
+        "UT" | "GMT" => return Ok(0),
+        "EST" => return Ok(-5 * 3_600),
+        "EDT" => return Ok(-4 * 3_600),
+        "CST" => return Ok(-6 * 3_600),
+        "CDT" => return Ok(-5 * 3_600),
+        "MST" => return Ok(-7 * 3_600),
+        "MDT" => return Ok(-6 * 3_600),
+        "PST" => return Ok(-8 * 3_600),
+        "PDT" => return Ok(-7 * 3_600),
+        _ => {}
+    }
+
+    let Some((sign, digits)) = zone.split_at_checked(1) else {
+        return Err("Invalid zone.");
+    };
+    let sign = match sign {
+        "+" => 1,
+        "-" => -1,
+        _ => return Err("Invalid zone."),
+    };
+    if digits.len() != 4 {
+        return Err("Invalid zone.");
+    }
+    let (Ok(hours), Ok(minutes)) = (digits[..2].parse::<i32>(), digits[2..].parse::<i32>()) else {
+        return Err("Invalid zone.");
+    };
+    Ok(sign * (hours * 3_600 + minutes * 60))
+}
+
+/// The three legacy HTTP-date formats (RFC 7231 §7.1.1.1 lists all
+/// three as historically valid, though only `IMF-fixdate` is preferred
+/// for generation): `IMF-fixdate`, RFC 850, and `asctime()`. A
+/// leading day-of-week is mandatory in all three and is not
+/// cross-checked against the parsed date.
+fn httpdate_string_to_utcdatetime(http_date: &str) -> Result<UTCDateTime, &'static str> {
+    http_date_string_to_utcdatetime(http_date)
+        .or_else(|_| rfc850_string_to_utcdatetime(http_date))
+        .or_else(|_| asctime_string_to_utcdatetime(http_date))
+}
+
+/// Obsolete RFC 850 HTTP-date (e.g.
+/// `"Sunday, 06-Nov-94 08:49:37 GMT"`). The two-digit year is windowed
+/// to a century per POSIX `strptime` `%y` rules: `00`-`68` -> 2000-2068,
+/// `69`-`99` -> 1969-1999.
+fn rfc850_string_to_utcdatetime(rfc850: &str) -> Result<UTCDateTime, &'static str> {
+    let Some((_weekday, rest)) = rfc850.split_once(", ") else {
+        return Err("Invalid RFC 850 datetime string.");
+    };
+
+    let mut tokens = rest.split_whitespace();
+    let (Some(date), Some(time), Some(zone)) = (tokens.next(), tokens.next(), tokens.next())
+    else {
+        return Err("Invalid RFC 850 datetime string.");
+    };
+    if tokens.next().is_some() || zone != "GMT" {
+        return Err("Invalid RFC 850 datetime string.");
+    }
+
+    let mut date = date.splitn(3, '-');
+    let (Some(day), Some(month), Some(year)) = (date.next(), date.next(), date.next()) else {
+        return Err("Invalid date.");
+    };
+    let Ok(day) = day.parse::<u8>() else {
+        return Err("Invalid day.");
+    };
+    let Ok(month) = rfc2822_monthname_to_month(month) else {
+        return Err("Invalid month.");
+    };
+    let Ok(year) = year.parse::<i32>() else {
+        return Err("Invalid year.");
+    };
+    if !(0..=99).contains(&year) {
+        return Err("Invalid year.");
+    }
+    let year = if year < 69 { year + 2000 } else { year + 1900 };
+    let Ok(date) = time::Date::from_calendar_date(year, month, day) else {
+        return Err("Invalid date.");
+    };
+
+    let mut clock = time.splitn(3, ':');
+    let (Some(hour), Some(minute), Some(second)) =
+        (clock.next(), clock.next(), clock.next())
+    else {
+        return Err("Invalid time.");
+    };
+    if clock.next().is_some() {
+        return Err("Invalid time.");
+    }
+    let (Ok(hour), Ok(minute), Ok(second)) =
+        (hour.parse::<u8>(), minute.parse::<u8>(), second.parse::<u8>())
+    else {
+        return Err("Invalid time.");
+    };
+    let Ok(time) = time::Time::from_hms(hour, minute, second) else {
+        return Err("Invalid time.");
+    };
+
+    Ok(UTCDateTime::from(time::OffsetDateTime::new_utc(date, time)))
+}
+
+/// Obsolete ANSI C `asctime()` HTTP-date (e.g.
+/// `"Sun Nov  6 08:49:37 1994"`). The day of month has no leading
+/// zero, space-padded instead (`splitn`/`split_whitespace` tolerate
+/// either).
+fn asctime_string_to_utcdatetime(asctime: &str) -> Result<UTCDateTime, &'static str> {
+    let mut tokens = asctime.split_whitespace();
+    let (Some(_weekday), Some(month), Some(day), Some(time), Some(year)) = (
+        tokens.next(),
+        tokens.next(),
+        tokens.next(),
+        tokens.next(),
+        tokens.next(),
+    ) else {
+        return Err("Invalid asctime datetime string.");
+    };
+    if tokens.next().is_some() {
+        return Err("Invalid asctime datetime string.");
+    }
+
+    let Ok(day) = day.parse::<u8>() else {
+        return Err("Invalid day.");
+    };
+    let Ok(month) = rfc2822_monthname_to_month(month) else {
+        return Err("Invalid month.");
+    };
+    let Ok(year) = year.parse::<i32>() else {
+        return Err("Invalid year.");
+    };
+    let Ok(date) = time::Date::from_calendar_date(year, month, day) else {
+        return Err("Invalid date.");
+    };
+
+    let mut clock = time.splitn(3, ':');
+    let (Some(hour), Some(minute), Some(second)) =
+        (clock.next(), clock.next(), clock.next())
+    else {
+        return Err("Invalid time.");
+    };
+    if clock.next().is_some() {
+        return Err("Invalid time.");
+    }
+    let (Ok(hour), Ok(minute), Ok(second)) =
+        (hour.parse::<u8>(), minute.parse::<u8>(), second.parse::<u8>())
+    else {
+        return Err("Invalid time.");
+    };
+    let Ok(time) = time::Time::from_hms(hour, minute, second) else {
+        return Err("Invalid time.");
+    };
+
+    Ok(UTCDateTime::from(time::OffsetDateTime::new_utc(date, time)))
+}
+
+/// Day of the week, as held (numerically) by [`UTCDateTime::weekday`] and
+/// [`LocalDateTime::weekday`].
+///
+/// Borrows the shape of chrono's `Weekday`: a small, exhaustive enum to
+/// pattern-match on (`if dt.weekday() == Weekday::Monday`) instead of
+/// juggling a raw `u32` and its `99`-means-unknown sentinel by hand.
+/// Discriminants match the existing `[0 = Sunday, 6 = Saturday]`
+/// numbering, so `weekday as u32`/`as usize` keeps working exactly like
+/// the field it's layered on top of.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Weekday {
+    Sunday = 0,
+    Monday = 1,
+    Tuesday = 2,
+    Wednesday = 3,
+    Thursday = 4,
+    Friday = 5,
+    Saturday = 6,
+}
+
+impl Weekday {
+    /// Number of days since Sunday, `[0;6]`.
+    #[must_use]
+    pub fn number_days_from_sunday(self) -> u32 {
+        self as u32
+    }
+
+    /// The following day, wrapping from Saturday back to Sunday.
+    #[must_use]
+    pub fn succ(self) -> Self {
+        match self {
+            Self::Sunday => Self::Monday,
+            Self::Monday => Self::Tuesday,
+            Self::Tuesday => Self::Wednesday,
+            Self::Wednesday => Self::Thursday,
+            Self::Thursday => Self::Friday,
+            Self::Friday => Self::Saturday,
+            Self::Saturday => Self::Sunday,
+        }
+    }
+
+    /// The preceding day, wrapping from Sunday back to Saturday.
+    #[must_use]
+    pub fn pred(self) -> Self {
+        match self {
+            Self::Sunday => Self::Saturday,
+            Self::Monday => Self::Sunday,
+            Self::Tuesday => Self::Monday,
+            Self::Wednesday => Self::Tuesday,
+            Self::Thursday => Self::Wednesday,
+            Self::Friday => Self::Thursday,
+            Self::Saturday => Self::Friday,
+        }
+    }
+}
+
+impl From<Weekday> for u32 {
+    fn from(weekday: Weekday) -> Self {
+        weekday as Self
+    }
+}
+
+impl TryFrom<u32> for Weekday {
+    type Error = &'static str;
+
+    fn try_from(weekday: u32) -> Result<Self, Self::Error> {
+        match weekday {
+            0 => Ok(Self::Sunday),
+            1 => Ok(Self::Monday),
+            2 => Ok(Self::Tuesday),
+            3 => Ok(Self::Wednesday),
+            4 => Ok(Self::Thursday),
+            5 => Ok(Self::Friday),
+            6 => Ok(Self::Saturday),
+            _ => Err("Weekday must be in [0;6]."),
+        }
+    }
+}
+
+impl From<time::Weekday> for Weekday {
+    fn from(weekday: time::Weekday) -> Self {
+        match weekday {
+            time::Weekday::Sunday => Self::Sunday,
+            time::Weekday::Monday => Self::Monday,
+            time::Weekday::Tuesday => Self::Tuesday,
+            time::Weekday::Wednesday => Self::Wednesday,
+            time::Weekday::Thursday => Self::Thursday,
+            time::Weekday::Friday => Self::Friday,
+            time::Weekday::Saturday => Self::Saturday,
+        }
+    }
+}
+
+/// Parse a loose, partially-specified date/time string. See
+/// [`UTCDateTime::from_time_travel_string`] for the accepted formats.
+fn parse_time_travel_string(datetime: &str) -> Result<UTCDateTime, &'static str> {
+    let tokens: Vec<&str> = datetime.split_whitespace().collect();
+
+    let (time, day, month, year) = match tokens.as_slice() {
+        [time, day, month, year] => (Some(*time), *day, *month, *year),
+        [day, month, year] => (None, *day, *month, *year),
+        [month, year] => (None, "1", *month, *year),
+        [year] => (None, "1", "January", *year),
+        _ => return Err("Invalid time travel string."),
+    };
+
+    let year: i32 = year.parse().map_err(|_| "Invalid year.")?;
+    let month = parse_month_name(month)?;
+    let day: u32 = day.parse().map_err(|_| "Invalid day.")?;
+    let (hour, minute, second) = time.map_or(Ok((0, 0, 0)), parse_time_of_day)?;
+
+    Ok(UTCDateTime::from_ymdhms(year, month, day, hour, minute, second))
+}
+
+/// Match `name` against [`super::MONAME`], case-insensitively, by full
+/// name or by an unambiguous leading abbreviation (e.g. `"Jan"`,
+/// `"january"`).
+fn parse_month_name(name: &str) -> Result<u32, &'static str> {
+    let name = name.to_ascii_lowercase();
+    super::MONAME
+        .iter()
+        .position(|month| {
+            let month = month.to_ascii_lowercase();
+            month == name || (name.len() >= 3 && month.starts_with(name.as_str()))
+        })
+        .map_or(Err("Invalid month name."), |i| Ok(i as u32 + 1))
+}
+
+/// Parse a bare time-of-day token as `HH:MM:SS`, `HH:MM`, or `HHMM`.
+fn parse_time_of_day(time: &str) -> Result<(u32, u32, u32), &'static str> {
+    if let Some((hour, rest)) = time.split_once(':') {
+        let hour: u32 = hour.parse().map_err(|_| "Invalid hour.")?;
+        return if let Some((minute, second)) = rest.split_once(':') {
+            let minute: u32 = minute.parse().map_err(|_| "Invalid minute.")?;
+            let second: u32 = second.parse().map_err(|_| "Invalid second.")?;
+            Ok((hour, minute, second))
+        } else {
+            let minute: u32 = rest.parse().map_err(|_| "Invalid minute.")?;
+            Ok((hour, minute, 0))
+        };
+    }
+
+    if time.len() == 4 && time.bytes().all(|b| b.is_ascii_digit()) {
+        let hour: u32 = time[..2].parse().map_err(|_| "Invalid hour.")?;
+        let minute: u32 = time[2..].parse().map_err(|_| "Invalid minute.")?;
+        return Ok((hour, minute, 0));
+    }
+
+    Err("Invalid time of day.")
+}
+
 fn weekday_for_ymdhms(
     year: i32,
     month: u32,
@@ -63,7 +523,7 @@ fn weekday_for_ymdhms(
     hour: u32,
     minute: u32,
     second: u32,
-) -> Result<u32, &'static str> {
+) -> Result<Weekday, &'static str> {
     let datetime = utcdatetime_to_offsetdatetime(&UTCDateTime {
         year,
         month,
@@ -72,8 +532,9 @@ fn weekday_for_ymdhms(
         hour,
         minute,
         second,
+        nanosecond: 0,
     })?;
-    Ok(u32::from(datetime.weekday().number_days_from_sunday()))
+    Ok(Weekday::from(datetime.weekday()))
 }
 
 #[cfg(not(tarpaulin_include))]
@@ -125,6 +586,7 @@ fn offsetdatetime_to_utcdatetime(datetime: &time::OffsetDateTime) -> UTCDateTime
         hour: u32::from(datetime.hour()),
         minute: u32::from(datetime.minute()),
         second: u32::from(datetime.second()),
+        nanosecond: datetime.nanosecond(),
     }
 }
 
@@ -149,6 +611,9 @@ pub struct UTCDateTime {
     pub minute: u32,
     /// `[0;59]`
     pub second: u32,
+    /// `[0;999_999_999]`. A parsed `:60` leap second becomes `:59` with
+    /// its fraction kept as is (`:60.25` reads as `:59.25`).
+    pub nanosecond: u32,
 }
 
 impl UTCDateTime {
@@ -172,7 +637,8 @@ impl UTCDateTime {
         minute: u32,
         second: u32,
     ) -> Self {
-        let weekday = weekday_for_ymdhms(year, month, day, hour, minute, second).unwrap_or(99);
+        let weekday = weekday_for_ymdhms(year, month, day, hour, minute, second)
+            .map_or(99, u32::from);
         Self {
             year,
             month,
@@ -181,6 +647,7 @@ impl UTCDateTime {
             hour,
             minute,
             second,
+            nanosecond: 0,
         }
     }
 
@@ -203,7 +670,58 @@ impl UTCDateTime {
             hour,
             minute,
             second,
+            nanosecond: 0,
+        }
+    }
+
+    /// From raw Year, Month, Day, Hour, Minute, Second values, validating
+    /// each component instead of silently accepting out-of-range ones.
+    ///
+    /// Month and day are checked together (so e.g. February 29 is only
+    /// accepted in leap years); second allows the `60` leap-second value.
+    ///
+    /// # Errors
+    ///
+    /// If month, day, hour, minute, or second is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use moontool::moon::UTCDateTime;
+    /// assert!(UTCDateTime::try_from_ymdhms(2024, 2, 29, 0, 0, 0).is_ok());
+    /// assert!(UTCDateTime::try_from_ymdhms(2023, 2, 29, 0, 0, 0).is_err());
+    /// assert!(UTCDateTime::try_from_ymdhms(2024, 13, 1, 0, 0, 0).is_err());
+    /// ```
+    pub fn try_from_ymdhms(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+    ) -> Result<Self, &'static str> {
+        let Ok(month_u8) = u8::try_from(month) else {
+            return Err("Invalid month.");
+        };
+        let Ok(month) = time::Month::try_from(month_u8) else {
+            return Err("Invalid month.");
+        };
+        let Ok(day_u8) = u8::try_from(day) else {
+            return Err("Invalid day.");
+        };
+        if time::Date::from_calendar_date(year, month, day_u8).is_err() {
+            return Err("Invalid day.");
+        }
+        if hour > 23 {
+            return Err("Invalid hour.");
+        }
+        if minute > 59 {
+            return Err("Invalid minute.");
+        }
+        if second > 60 {
+            return Err("Invalid second.");
         }
+        Ok(Self::from_ymdhms(year, u32::from(month_u8), day, hour, minute, second))
     }
 
     /// From ISO 8601 date or datetime string.
@@ -225,6 +743,150 @@ impl UTCDateTime {
         Self::try_from(iso_string)
     }
 
+    /// From a loose, partially-specified "time travel" string, the kind
+    /// a user would type interactively to jump to an arbitrary moment
+    /// (e.g. `"1977"` or `"April 1990"`), without having to pre-format
+    /// an exact timestamp.
+    ///
+    /// Missing components default to `00` for time fields, `1` for day,
+    /// and January for month. Accepts, from most to least specific:
+    ///
+    /// - `HH:MM:SS DD Month YYYY`
+    /// - `HH:MM DD Month YYYY`
+    /// - `HHMM DD Month YYYY`
+    /// - `DD Month YYYY`
+    /// - `Month YYYY`
+    /// - `YYYY`
+    ///
+    /// `Month` is a name or unambiguous abbreviation (e.g. `Jan`), matched
+    /// case-insensitively.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use moontool::moon::UTCDateTime;
+    /// let dt = UTCDateTime::from_time_travel_string("14:21 4 May 2024").unwrap();
+    /// assert_eq!(dt, UTCDateTime::from_ymdhms(2024, 5, 4, 14, 21, 0));
+    ///
+    /// let dt = UTCDateTime::from_time_travel_string("April 1990").unwrap();
+    /// assert_eq!(dt, UTCDateTime::from_ymdhms(1990, 4, 1, 0, 0, 0));
+    ///
+    /// let dt = UTCDateTime::from_time_travel_string("1977").unwrap();
+    /// assert_eq!(dt, UTCDateTime::from_ymdhms(1977, 1, 1, 0, 0, 0));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Errors if the string doesn't match one of the accepted formats.
+    pub fn from_time_travel_string(datetime: &str) -> Result<Self, &'static str> {
+        parse_time_travel_string(datetime)
+    }
+
+    /// From RFC 2822 (e.g., email `Date:` headers) or HTTP-date (the
+    /// `IMF-fixdate` format from RFC 7231 §7.1.1.1, e.g. the
+    /// `Date:`/`Last-Modified:` HTTP headers) string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use moontool::moon::UTCDateTime;
+    /// let _ = UTCDateTime::from_rfc2822_string("Sun, 20 Jul 1969 20:17:40 +0000").unwrap();
+    /// let _ = UTCDateTime::from_rfc2822_string("Sun, 20 Jul 1969 20:17:40 GMT").unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Errors if input string is in invalid format.
+    pub fn from_rfc2822_string(datetime: &str) -> Result<Self, &'static str> {
+        rfc2822_string_to_utcdatetime(datetime)
+            .or_else(|_| http_date_string_to_utcdatetime(datetime))
+    }
+
+    /// From an RFC 2822 datetime string, more tolerantly than
+    /// [`Self::from_rfc2822_string`]: the leading day-of-week is
+    /// optional (and not verified against the parsed date), offsets may
+    /// be numeric (`±HHMM`) or one of the obsolete zone abbreviations
+    /// (`UT`/`GMT`/`EST`/`EDT`/`CST`/`CDT`/`MST`/`MDT`/`PST`/`PDT`), and
+    /// runs of whitespace between tokens are tolerated. Embedded
+    /// parenthetical comments (e.g. `09(comment):55`) are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use moontool::moon::UTCDateTime;
+    /// let dt = UTCDateTime::from_rfc2822("Fri, 21 Nov 1997 09:55:06 -0600").unwrap();
+    /// assert_eq!(dt, UTCDateTime::from_ymdhms(1997, 11, 21, 15, 55, 6));
+    ///
+    /// let dt = UTCDateTime::from_rfc2822("21 Nov 1997 09:55:06 EST").unwrap();
+    /// assert_eq!(dt, UTCDateTime::from_ymdhms(1997, 11, 21, 14, 55, 6));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Errors if input string is in invalid format.
+    pub fn from_rfc2822(datetime: &str) -> Result<Self, &'static str> {
+        rfc2822_string_to_utcdatetime_tolerant(datetime)
+    }
+
+    /// From an HTTP-date string, accepting all three legacy formats
+    /// historically used for HTTP `Date:`/`Last-Modified:` headers: the
+    /// preferred RFC 7231 `IMF-fixdate`, the obsolete RFC 850 format
+    /// (two-digit year windowed to a century), and the obsolete ANSI C
+    /// `asctime()` format (space-padded day). All three are fixed at
+    /// GMT, so no offset math is involved; the leading day-of-week is
+    /// mandatory in every format and is not cross-checked against the
+    /// parsed date.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use moontool::moon::UTCDateTime;
+    /// let a = UTCDateTime::from_httpdate("Sun, 06 Nov 1994 08:49:37 GMT").unwrap();
+    /// let b = UTCDateTime::from_httpdate("Sunday, 06-Nov-94 08:49:37 GMT").unwrap();
+    /// let c = UTCDateTime::from_httpdate("Sun Nov  6 08:49:37 1994").unwrap();
+    /// assert_eq!(a, b);
+    /// assert_eq!(a, c);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Errors if input string matches none of the three formats.
+    pub fn from_httpdate(datetime: &str) -> Result<Self, &'static str> {
+        httpdate_string_to_utcdatetime(datetime)
+    }
+
+    /// Render as an RFC 2822 datetime string, always with a `+0000`
+    /// (UTC) offset.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use moontool::moon::UTCDateTime;
+    /// let dt = UTCDateTime::from_ymdhms(1997, 11, 21, 15, 55, 6);
+    /// assert_eq!(dt.to_rfc2822(), "Fri, 21 Nov 1997 15:55:06 +0000");
+    /// ```
+    #[must_use]
+    pub fn to_rfc2822(&self) -> String {
+        format!(
+            "{}, {:0>2} {} {:0>4} {:0>2}:{:0>2}:{:0>2} +0000",
+            self.dayname_abbreviated(),
+            self.day,
+            self.monthname_abbreviated(),
+            self.year,
+            self.hour,
+            self.minute,
+            self.second
+        )
+    }
+
+    fn dayname_abbreviated(&self) -> &'static str {
+        &super::DAYNAME[self.weekday() as usize][..3]
+    }
+
+    fn monthname_abbreviated(&self) -> &'static str {
+        &super::MONAME[(self.month - 1) as usize][..3]
+    }
+
     /// Convert Unix timestamp to `UTCDateTime`.
     ///
     /// # Errors
@@ -246,6 +908,12 @@ impl UTCDateTime {
     }
 
     /// Convert astronomical Julian date to `UTCDateTime`.
+    ///
+    /// The resulting [`Self::nanosecond`] is always `0`: the underlying
+    /// conversion rounds to the nearest whole second, so there's no
+    /// sub-second remainder left to recover. Use [`Self::to_julian_date`]
+    /// for the other direction, which does carry any fractional second
+    /// through.
     #[must_use]
     pub fn from_julian_date(julian_date: f64) -> Self {
         super::jtouct(julian_date)
@@ -292,7 +960,7 @@ impl UTCDateTime {
     /// ```
     #[must_use]
     pub fn to_julian_date(&self) -> f64 {
-        super::jtime(self)
+        super::jtime(self) + f64::from(self.nanosecond) / 1_000_000_000.0 / 86400.0
     }
 
     /// Convert `UTCDateTime` to civil Julian date.
@@ -326,19 +994,169 @@ impl UTCDateTime {
     pub fn to_civil_julian_date(&self) -> f64 {
         self.to_julian_date() + 0.5
     }
-}
-
-impl FromStr for UTCDateTime {
-    type Err = &'static str;
 
-    fn from_str(datetime: &str) -> Result<Self, Self::Err> {
-        let dt = iso_datetime_string_to_utcdatetime(datetime)?;
-        Ok(dt)
+    /// Day of year, `[1;366]`.
+    ///
+    /// # Errors
+    ///
+    /// Errors if `self`'s fields are invalid (e.g. `2024-01-42`).
+    pub fn ordinal(&self) -> Result<u32, &'static str> {
+        let datetime = utcdatetime_to_offsetdatetime(self)?;
+        Ok(u32::from(datetime.ordinal()))
     }
-}
 
-impl TryFrom<&str> for UTCDateTime {
-    type Error = &'static str;
+    /// ISO 8601 day of week.
+    ///
+    /// - 1 = Monday
+    /// - ...
+    /// - 7 = Sunday
+    ///
+    /// Distinct from [`Self::weekday`], which is offset from Sunday.
+    ///
+    /// # Errors
+    ///
+    /// Errors if `self`'s fields are invalid (e.g. `2024-01-42`).
+    pub fn iso_weekday(&self) -> Result<u32, &'static str> {
+        let datetime = utcdatetime_to_offsetdatetime(self)?;
+        Ok(u32::from(datetime.weekday().number_from_monday()))
+    }
+
+    /// ISO 8601 week date, as `(ISO year, ISO week number)`.
+    ///
+    /// The ISO year can differ from [`Self::year`] for dates near the
+    /// year boundary (e.g. December 31st can fall in week 1 of the
+    /// following ISO year).
+    ///
+    /// # Errors
+    ///
+    /// Errors if `self`'s fields are invalid (e.g. `2024-01-42`).
+    pub fn iso_week(&self) -> Result<(i32, u8), &'static str> {
+        let datetime = utcdatetime_to_offsetdatetime(self)?;
+        let (iso_year, iso_week, _) = datetime.to_iso_week_date();
+        Ok((iso_year, iso_week))
+    }
+
+    /// Typed day of the week, recomputed from `year`/`month`/`day` (not
+    /// read from the cached [`Self::weekday`] field), so it stays
+    /// correct even if a caller mutates those fields directly after
+    /// construction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `year`/`month`/`day` don't describe a valid date.
+    #[must_use]
+    pub fn weekday(&self) -> Weekday {
+        weekday_for_ymdhms(self.year, self.month, self.day, self.hour, self.minute, self.second)
+            .expect("fields do not describe a valid date")
+    }
+
+    /// Render this datetime using a small `strftime`-style pattern:
+    /// `%Y %m %d %H %M %S %A %B %j`, plus `%%` for a literal `%`.
+    ///
+    /// This is the crate's only `strftime`-style formatter; an equivalent
+    /// was briefly duplicated on the (since removed) top-level
+    /// `datetime::UTCDateTime` — use this one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use moontool::moon::UTCDateTime;
+    /// let dt = UTCDateTime::from_ymdhms(2024, 6, 14, 0, 0, 0);
+    /// assert_eq!(dt.format("%A, %B %d %Y").unwrap(), "Friday, June 14 2024");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Errors if `pattern` contains an unsupported specifier, or if
+    /// `self`'s fields are invalid (e.g. `2024-01-42`).
+    pub fn format(&self, pattern: &str) -> Result<String, &'static str> {
+        let format = strftime_pattern_to_time_format(pattern)?;
+        let format =
+            time::format_description::parse(&format).map_err(|_| "Invalid format pattern.")?;
+        let datetime = utcdatetime_to_offsetdatetime(self)?;
+        datetime.format(&format).map_err(|_| "Error formatting datetime.")
+    }
+
+    /// Parse a datetime out of `input`, according to the same
+    /// `strftime`-style pattern accepted by [`Self::format`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use moontool::moon::UTCDateTime;
+    /// let dt = UTCDateTime::parse_from_str("2024-06-14", "%Y-%m-%d").unwrap();
+    /// assert_eq!(dt, UTCDateTime::from_ymdhms(2024, 6, 14, 0, 0, 0));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Errors if `pattern` contains an unsupported specifier, or if
+    /// `input` doesn't match `pattern`.
+    pub fn parse_from_str(input: &str, pattern: &str) -> Result<Self, &'static str> {
+        let format = strftime_pattern_to_time_format(pattern)?;
+        let format =
+            time::format_description::parse(&format).map_err(|_| "Invalid format pattern.")?;
+
+        // `PrimitiveDateTime::parse` requires both date and time
+        // components in the format, even if the caller's pattern only
+        // describes a date (e.g. `%Y-%m-%d`). Fall back to `Date::parse`
+        // plus midnight in that case.
+        if pattern.contains("%H") || pattern.contains("%M") || pattern.contains("%S") {
+            let Ok(datetime) = time::PrimitiveDateTime::parse(input, &format) else {
+                return Err("Error parsing datetime.");
+            };
+            Ok(Self::from(datetime.assume_utc()))
+        } else {
+            let Ok(date) = time::Date::parse(input, &format) else {
+                return Err("Error parsing datetime.");
+            };
+            let datetime = time::PrimitiveDateTime::new(date, time::Time::MIDNIGHT);
+            Ok(Self::from(datetime.assume_utc()))
+        }
+    }
+}
+
+/// Translates a small `strftime`-style pattern (`%Y %m %d %H %M %S %A %B
+/// %j %%`) into the `time` crate's own format-description syntax, so
+/// [`UTCDateTime::format`]/[`UTCDateTime::parse_from_str`] can delegate
+/// the actual formatting/parsing to `time` — keeping, per this module's
+/// header comment, everything time-rs confined in here.
+fn strftime_pattern_to_time_format(pattern: &str) -> Result<String, &'static str> {
+    let mut out = String::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('Y') => out.push_str("[year]"),
+            Some('m') => out.push_str("[month]"),
+            Some('d') => out.push_str("[day]"),
+            Some('H') => out.push_str("[hour]"),
+            Some('M') => out.push_str("[minute]"),
+            Some('S') => out.push_str("[second]"),
+            Some('A') => out.push_str("[weekday repr:long]"),
+            Some('B') => out.push_str("[month repr:long]"),
+            Some('j') => out.push_str("[ordinal]"),
+            Some('%') => out.push('%'),
+            _ => return Err("Unknown format specifier."),
+        }
+    }
+    Ok(out)
+}
+
+impl FromStr for UTCDateTime {
+    type Err = &'static str;
+
+    fn from_str(datetime: &str) -> Result<Self, Self::Err> {
+        let dt = iso_datetime_string_to_utcdatetime(datetime)?;
+        Ok(dt)
+    }
+}
+
+impl TryFrom<&str> for UTCDateTime {
+    type Error = &'static str;
 
     fn try_from(datetime: &str) -> Result<Self, Self::Error> {
         datetime.parse()
@@ -363,9 +1181,105 @@ impl fmt::Display for UTCDateTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{:0>4}-{:0>2}-{:0>2}T{:0>2}:{:0>2}:{:0>2}Z",
+            "{:0>4}-{:0>2}-{:0>2}T{:0>2}:{:0>2}:{:0>2}",
             self.year, self.month, self.day, self.hour, self.minute, self.second
+        )?;
+        if self.nanosecond != 0 {
+            let fractional = format!("{:0>9}", self.nanosecond);
+            write!(f, ".{}", fractional.trim_end_matches('0'))?;
+        }
+        write!(f, "Z")
+    }
+}
+
+/// `dt + Duration::from_secs(86_400)` is a day later, round-tripped
+/// through the Julian date rather than the (fallible) Unix timestamp, so
+/// this never errors even far outside 1970-2038-ish ranges.
+///
+/// # Examples
+///
+/// ```
+/// # use std::time::Duration;
+/// # use moontool::moon::UTCDateTime;
+/// #
+/// let today: UTCDateTime = "2024-06-14T00:00:00".parse().unwrap();
+/// let tomorrow = today + Duration::from_secs(86_400);
+///
+/// assert_eq!(tomorrow, "2024-06-15T00:00:00".parse().unwrap());
+/// ```
+impl std::ops::Add<std::time::Duration> for UTCDateTime {
+    type Output = Self;
+
+    fn add(self, duration: std::time::Duration) -> Self::Output {
+        let days = duration.as_secs_f64() / 86_400.0;
+        Self::from_julian_date(self.to_julian_date() + days)
+    }
+}
+
+/// The `Duration`-subtracting counterpart of `Add`, above.
+impl std::ops::Sub<std::time::Duration> for UTCDateTime {
+    type Output = Self;
+
+    fn sub(self, duration: std::time::Duration) -> Self::Output {
+        let days = duration.as_secs_f64() / 86_400.0;
+        Self::from_julian_date(self.to_julian_date() - days)
+    }
+}
+
+/// `end - start` is the (possibly negative) elapsed time between two
+/// instants, instead of forcing callers to drop to raw timestamps and
+/// subtract those by hand.
+///
+/// # Examples
+///
+/// ```
+/// # use moontool::moon::UTCDateTime;
+/// #
+/// let start: UTCDateTime = "2024-06-14T00:00:00".parse().unwrap();
+/// let end: UTCDateTime = "2024-06-15T12:00:00".parse().unwrap();
+///
+/// assert_eq!(end - start, time::Duration::hours(36));
+/// assert_eq!(start - end, time::Duration::hours(-36));
+/// ```
+impl std::ops::Sub for UTCDateTime {
+    type Output = time::Duration;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let days = self.to_julian_date() - rhs.to_julian_date();
+        time::Duration::seconds_f64(days * 86_400.0)
+    }
+}
+
+/// Orders by (year, month, day, hour, minute, second, nanosecond),
+/// ignoring [`Self::weekday`] — it's derived from the other fields, so
+/// two otherwise-equal instants shouldn't compare differently just
+/// because one of them carries the `99` sentinel.
+impl PartialOrd for UTCDateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for UTCDateTime {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (
+            self.year,
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second,
+            self.nanosecond,
         )
+            .cmp(&(
+                other.year,
+                other.month,
+                other.day,
+                other.hour,
+                other.minute,
+                other.second,
+                other.nanosecond,
+            ))
     }
 }
 
@@ -406,6 +1320,103 @@ pub struct LocalDateTime {
     pub second: u32,
 }
 
+impl LocalDateTime {
+    /// Day of year, `[1;366]`.
+    ///
+    /// # Errors
+    ///
+    /// Errors if `self`'s fields are invalid (e.g. `2024-01-42`).
+    pub fn ordinal(&self) -> Result<u32, &'static str> {
+        let datetime = utcdatetime_to_offsetdatetime(&self.as_utcdatetime())?;
+        Ok(u32::from(datetime.ordinal()))
+    }
+
+    /// ISO 8601 day of week.
+    ///
+    /// - 1 = Monday
+    /// - ...
+    /// - 7 = Sunday
+    ///
+    /// Distinct from [`Self::weekday`], which is offset from Sunday.
+    ///
+    /// # Errors
+    ///
+    /// Errors if `self`'s fields are invalid (e.g. `2024-01-42`).
+    pub fn iso_weekday(&self) -> Result<u32, &'static str> {
+        let datetime = utcdatetime_to_offsetdatetime(&self.as_utcdatetime())?;
+        Ok(u32::from(datetime.weekday().number_from_monday()))
+    }
+
+    /// ISO 8601 week date, as `(ISO year, ISO week number)`.
+    ///
+    /// The ISO year can differ from [`Self::year`] for dates near the
+    /// year boundary (e.g. December 31st can fall in week 1 of the
+    /// following ISO year).
+    ///
+    /// # Errors
+    ///
+    /// Errors if `self`'s fields are invalid (e.g. `2024-01-42`).
+    pub fn iso_week(&self) -> Result<(i32, u8), &'static str> {
+        let datetime = utcdatetime_to_offsetdatetime(&self.as_utcdatetime())?;
+        let (iso_year, iso_week, _) = datetime.to_iso_week_date();
+        Ok((iso_year, iso_week))
+    }
+
+    /// `self`'s date/time fields, reinterpreted as a [`UTCDateTime`],
+    /// so calendar-only helpers like [`Self::ordinal`] can reuse
+    /// [`utcdatetime_to_offsetdatetime`] instead of duplicating it.
+    /// Not a real UTC instant — `LocalDateTime` carries no offset, so
+    /// there's nothing to convert.
+    fn as_utcdatetime(&self) -> UTCDateTime {
+        UTCDateTime {
+            year: self.year,
+            month: self.month,
+            day: self.day,
+            weekday: self.weekday,
+            hour: self.hour,
+            minute: self.minute,
+            second: self.second,
+            nanosecond: 0,
+        }
+    }
+
+    /// Typed day of the week, recomputed from `year`/`month`/`day` (not
+    /// read from the cached [`Self::weekday`] field), so it stays
+    /// correct even if a caller mutates those fields directly after
+    /// construction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `year`/`month`/`day` don't describe a valid date.
+    #[must_use]
+    pub fn weekday(&self) -> Weekday {
+        weekday_for_ymdhms(self.year, self.month, self.day, self.hour, self.minute, self.second)
+            .expect("fields do not describe a valid date")
+    }
+}
+
+/// Orders by (year, month, day, hour, minute, second), ignoring
+/// [`Self::weekday`] — see the identical rationale on [`UTCDateTime`]'s
+/// `Ord` impl.
+impl PartialOrd for LocalDateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for LocalDateTime {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.year, self.month, self.day, self.hour, self.minute, self.second).cmp(&(
+            other.year,
+            other.month,
+            other.day,
+            other.hour,
+            other.minute,
+            other.second,
+        ))
+    }
+}
+
 impl TryFrom<&UTCDateTime> for LocalDateTime {
     type Error = &'static str;
 
@@ -415,139 +1426,1626 @@ impl TryFrom<&UTCDateTime> for LocalDateTime {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Like [`UTCDateTime`]'s `Display`, but without the `Z` suffix, since a
+/// [`LocalDateTime`] carries no UTC offset of its own.
+fn local_datetime_to_iso_string(datetime: &LocalDateTime) -> String {
+    format!(
+        "{:0>4}-{:0>2}-{:0>2}T{:0>2}:{:0>2}:{:0>2}",
+        datetime.year, datetime.month, datetime.day, datetime.hour, datetime.minute, datetime.second
+    )
+}
 
-    macro_rules! assert_almost_eq {
-        ($a:expr, $b:expr) => {
-            assert!(($a - $b).abs() < f64::EPSILON, "{} != {}", $a, $b);
+/// Inverse of [`local_datetime_to_iso_string`], reusing
+/// [`iso_datetime_string_to_utcdatetime`] to do the actual parsing; the
+/// fields are then taken as-is, rather than converted to UTC.
+fn iso_string_to_local_datetime(iso_datetime: &str) -> Result<LocalDateTime, &'static str> {
+    let datetime = iso_datetime_string_to_utcdatetime(iso_datetime)?;
+    Ok(LocalDateTime {
+        year: datetime.year,
+        month: datetime.month,
+        day: datetime.day,
+        weekday: datetime.weekday,
+        hour: datetime.hour,
+        minute: datetime.minute,
+        second: datetime.second,
+    })
+}
+
+fn offset_seconds_to_string(offset_seconds: i32) -> String {
+    let sign = if offset_seconds < 0 { '-' } else { '+' };
+    let total_minutes = offset_seconds.unsigned_abs() / 60;
+    format!("{sign}{:0>2}:{:0>2}", total_minutes / 60, total_minutes % 60)
+}
+
+fn utcdatetime_to_fixedoffsetdatetime(
+    datetime: &UTCDateTime,
+    offset_seconds: i32,
+) -> Result<FixedOffsetDateTime, &'static str> {
+    let Ok(offset) = time::UtcOffset::from_whole_seconds(offset_seconds) else {
+        return Err("Offset is out of range.");
+    };
+    let local = utcdatetime_to_offsetdatetime(datetime)?.to_offset(offset);
+    Ok(FixedOffsetDateTime {
+        year: local.year(),
+        month: u32::from(local.month() as u8),
+        day: u32::from(local.day()),
+        weekday: u32::from(Weekday::from(local.weekday())),
+        hour: u32::from(local.hour()),
+        minute: u32::from(local.minute()),
+        second: u32::from(local.second()),
+        offset_seconds,
+    })
+}
+
+fn fixedoffsetdatetime_to_utcdatetime(
+    datetime: &FixedOffsetDateTime,
+) -> Result<UTCDateTime, &'static str> {
+    let Ok(offset) = time::UtcOffset::from_whole_seconds(datetime.offset_seconds) else {
+        return Err("Offset is out of range.");
+    };
+    let Ok(month) = time::Month::try_from(datetime.month as u8) else {
+        return Err("Invalid month.");
+    };
+    let Ok(date) = time::Date::from_calendar_date(datetime.year, month, datetime.day as u8)
+    else {
+        return Err("Invalid date.");
+    };
+    let Ok(time) =
+        time::Time::from_hms(datetime.hour as u8, datetime.minute as u8, datetime.second as u8)
+    else {
+        return Err("Invalid time.");
+    };
+    let local = time::OffsetDateTime::new_in_offset(date, time, offset);
+    Ok(UTCDateTime::from(local.to_offset(time::UtcOffset::UTC)))
+}
+
+fn iso_string_to_fixedoffsetdatetime(
+    iso_datetime: &str,
+) -> Result<FixedOffsetDateTime, &'static str> {
+    let iso_datetime = normalize_leap_second(iso_datetime);
+    let Ok(datetime) = parse_datetime(&iso_datetime) else {
+        return Err("Invalid datetime string.");
+    };
+    Ok(FixedOffsetDateTime {
+        year: datetime.year(),
+        month: u32::from(datetime.month() as u8),
+        day: u32::from(datetime.day()),
+        weekday: u32::from(Weekday::from(datetime.weekday())),
+        hour: u32::from(datetime.hour()),
+        minute: u32::from(datetime.minute()),
+        second: u32::from(datetime.second()),
+        offset_seconds: datetime.offset().whole_seconds(),
+    })
+}
+
+/// The result of resolving a [`LocalDateTime`] wall-clock time back to
+/// UTC under a [`DstRule`].
+///
+/// Unlike UTC→local (always unambiguous), a local wall clock can name a
+/// time that either doesn't exist (the spring-forward gap) or exists
+/// twice (the repeated fall-back hour), so the conversion can't just
+/// return a single [`UTCDateTime`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LocalResult {
+    /// No UTC instant maps to this local time; it falls in the
+    /// spring-forward gap.
+    None,
+    /// Exactly one UTC instant maps to this local time.
+    Single(UTCDateTime),
+    /// Two UTC instants map to this local time (the repeated fall-back
+    /// hour), in chronological order.
+    Ambiguous(UTCDateTime, UTCDateTime),
+}
+
+/// A timezone rule with a single yearly spring-forward/fall-back
+/// daylight-saving transition, e.g. standard `+08:00` / daylight
+/// `+09:00`, switching on fixed calendar dates.
+///
+/// This is an explicit alternative to [`LocalDateTime::try_from`]'s
+/// `UTCDateTime` conversion (which asks the host OS for *its* current
+/// timezone): callers who need DST-correct conversions for a timezone
+/// other than the host's (e.g. to display moonrise/moonset in a
+/// user-chosen zone) supply the rule explicitly instead.
+///
+/// Both transitions are taken to happen at 02:00 local time: spring
+/// forward at 02:00 standard time (the clock jumps straight to 03:00),
+/// fall back at 02:00 daylight time (the clock falls back to 01:00).
+/// This matches how DST is commonly defined (e.g. US rules), but isn't
+/// configurable to any other transition hour.
+///
+/// # Examples
+///
+/// ```rust
+/// # use moontool::moon::{DstRule, LocalResult, UTCDateTime};
+/// let rule = DstRule {
+///     standard_offset_seconds: 8 * 3_600,
+///     daylight_offset_seconds: 9 * 3_600,
+///     dst_start: (3, 30),
+///     dst_end: (10, 26),
+/// };
+///
+/// let winter = UTCDateTime::from_ymdhms(2024, 1, 1, 0, 0, 0);
+/// let local = rule.utc_to_local(&winter).unwrap();
+/// assert_eq!(local.hour, 8);
+///
+/// let summer = UTCDateTime::from_ymdhms(2024, 6, 1, 0, 0, 0);
+/// let local = rule.utc_to_local(&summer).unwrap();
+/// assert_eq!(local.hour, 9);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DstRule {
+    /// UTC offset in effect outside of daylight saving, in seconds (e.g.
+    /// `28_800` for `+08:00`).
+    pub standard_offset_seconds: i32,
+    /// UTC offset in effect during daylight saving, in seconds (e.g.
+    /// `32_400` for `+09:00`).
+    pub daylight_offset_seconds: i32,
+    /// `(month, day)` on which daylight saving starts.
+    pub dst_start: (u32, u32),
+    /// `(month, day)` on which daylight saving ends.
+    pub dst_end: (u32, u32),
+}
+
+impl DstRule {
+    /// The UTC offset in effect at a given UTC instant.
+    ///
+    /// # Errors
+    ///
+    /// Errors if `utc`'s fields are invalid, or if [`Self::dst_start`]/
+    /// [`Self::dst_end`] don't describe a valid month/day for `utc`'s
+    /// year.
+    pub fn offset_seconds_at(&self, utc: &UTCDateTime) -> Result<i32, &'static str> {
+        let instant = utc.to_timestamp()?;
+        let start = self.dst_start_timestamp(utc.year)?;
+        let end = self.dst_end_timestamp(utc.year)?;
+
+        let in_daylight = if start <= end {
+            instant >= start && instant < end
+        } else {
+            // Southern-hemisphere-style rule: the daylight interval
+            // wraps around the turn of the year.
+            instant >= start || instant < end
         };
+
+        Ok(if in_daylight {
+            self.daylight_offset_seconds
+        } else {
+            self.standard_offset_seconds
+        })
     }
 
-    // Date/time utils
+    /// Convert a UTC instant to this rule's local time. Always
+    /// unambiguous.
+    ///
+    /// # Errors
+    ///
+    /// Errors if `utc`'s fields are invalid, or the resulting local
+    /// instant would be out of [`UTCDateTime::from_timestamp`]'s range.
+    pub fn utc_to_local(&self, utc: &UTCDateTime) -> Result<LocalDateTime, &'static str> {
+        let offset = self.offset_seconds_at(utc)?;
+        let instant = utc.to_timestamp()? + i64::from(offset);
+        let local = UTCDateTime::from_timestamp(instant)?;
+        Ok(LocalDateTime {
+            year: local.year,
+            month: local.month,
+            day: local.day,
+            weekday: local.weekday,
+            hour: local.hour,
+            minute: local.minute,
+            second: local.second,
+        })
+    }
 
-    #[test]
-    fn utcdatetime_to_timestamp_regular() {
-        let t =
-            utcdatetime_to_timestamp(&UTCDateTime::from_ymdhms(2024, 4, 30, 18, 21, 42)).unwrap();
+    /// Resolve a local wall-clock time back to UTC under this rule.
+    ///
+    /// # Errors
+    ///
+    /// Errors if `local`'s fields are invalid.
+    pub fn local_to_utc(&self, local: &LocalDateTime) -> Result<LocalResult, &'static str> {
+        let naive = UTCDateTime::from_ymdhms(
+            local.year,
+            local.month,
+            local.day,
+            local.hour,
+            local.minute,
+            local.second,
+        );
+        let naive_instant = naive.to_timestamp()?;
+
+        let standard_instant = naive_instant - i64::from(self.standard_offset_seconds);
+        let daylight_instant = naive_instant - i64::from(self.daylight_offset_seconds);
+
+        let standard_utc = UTCDateTime::from_timestamp(standard_instant)?;
+        let daylight_utc = UTCDateTime::from_timestamp(daylight_instant)?;
+
+        let standard_valid = self.offset_seconds_at(&standard_utc)? == self.standard_offset_seconds;
+        let daylight_valid = self.offset_seconds_at(&daylight_utc)? == self.daylight_offset_seconds;
+
+        Ok(match (standard_valid, daylight_valid) {
+            (true, true) if standard_instant <= daylight_instant => {
+                LocalResult::Ambiguous(standard_utc, daylight_utc)
+            }
+            (true, true) => LocalResult::Ambiguous(daylight_utc, standard_utc),
+            (true, false) => LocalResult::Single(standard_utc),
+            (false, true) => LocalResult::Single(daylight_utc),
+            (false, false) => LocalResult::None,
+        })
+    }
 
-        assert_eq!(t, 1_714_501_302);
+    fn dst_start_timestamp(&self, year: i32) -> Result<i64, &'static str> {
+        let (month, day) = self.dst_start;
+        let local = UTCDateTime::try_from_ymdhms(year, month, day, 2, 0, 0)?;
+        Ok(local.to_timestamp()? - i64::from(self.standard_offset_seconds))
     }
 
-    #[test]
-    fn utcdatetime_to_timestamp_zero() {
-        let t = utcdatetime_to_timestamp(&UTCDateTime::from_ymdhms(1970, 1, 1, 0, 0, 0)).unwrap();
+    fn dst_end_timestamp(&self, year: i32) -> Result<i64, &'static str> {
+        let (month, day) = self.dst_end;
+        let local = UTCDateTime::try_from_ymdhms(year, month, day, 2, 0, 0)?;
+        Ok(local.to_timestamp()? - i64::from(self.daylight_offset_seconds))
+    }
+}
 
-        assert_eq!(t, 0);
+/// A date and time paired with an explicit, signed UTC offset, unlike
+/// [`UTCDateTime`] (always UTC) or [`LocalDateTime`] (always the
+/// system's *current* local offset). Useful when a caller wants to
+/// round-trip the offset that was present in a parsed string (e.g.
+/// `1964-12-20T05:35:00+01:00`) instead of having it silently collapsed
+/// to UTC.
+///
+/// # Examples
+///
+/// ```rust
+/// # use moontool::moon::{FixedOffsetDateTime, UTCDateTime};
+/// let dt: FixedOffsetDateTime = "1964-12-20T05:35:00+01:00".parse().unwrap();
+/// assert_eq!(dt.to_string(), "1964-12-20T05:35:00+01:00");
+///
+/// let utc = dt.to_utc();
+/// assert_eq!(utc, UTCDateTime::from_ymdhms(1964, 12, 20, 4, 35, 0));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FixedOffsetDateTime {
+    pub year: i32,
+    /// `[1;12]`
+    pub month: u32,
+    /// `[1;31]`
+    pub day: u32,
+    /// `[0 = Sunday, 6 = Saturday]`
+    pub weekday: u32,
+    /// `[0;23]`
+    pub hour: u32,
+    /// `[0;59]`
+    pub minute: u32,
+    /// `[0;59]`
+    pub second: u32,
+    /// Signed offset from UTC, in seconds (e.g. `3_600` for `+01:00`).
+    pub offset_seconds: i32,
+}
+
+impl FixedOffsetDateTime {
+    /// From a [`UTCDateTime`] plus a signed offset in seconds.
+    ///
+    /// # Errors
+    ///
+    /// Errors if `offset_seconds` is outside `UtcOffset`'s representable
+    /// range (±(23:59:59)), or if `datetime`'s fields are invalid.
+    pub fn from_utc(datetime: &UTCDateTime, offset_seconds: i32) -> Result<Self, &'static str> {
+        utcdatetime_to_fixedoffsetdatetime(datetime, offset_seconds)
+    }
+
+    /// Convert back to UTC, undoing the offset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this instance's fields are invalid (e.g. built by hand
+    /// with an out-of-range `month`/`day`, or a stale `offset_seconds`
+    /// no longer representable).
+    #[must_use]
+    pub fn to_utc(&self) -> UTCDateTime {
+        fixedoffsetdatetime_to_utcdatetime(self)
+            .expect("FixedOffsetDateTime fields should describe a valid date/time/offset")
+    }
+
+    /// From an ISO 8601 / RFC 3339 datetime string, preserving whatever
+    /// offset (`Z`, `+HH:MM`, `-HH:MM`) was present, rather than
+    /// normalizing it away to UTC.
+    ///
+    /// # Errors
+    ///
+    /// Errors if input string is in invalid format.
+    pub fn from_iso_string(iso_string: &str) -> Result<Self, &'static str> {
+        Self::try_from(iso_string)
+    }
+
+    /// Typed day of the week, against the *local* wall-clock components
+    /// (i.e. the offset-adjusted date, not the underlying UTC instant).
+    /// Recomputed from `year`/`month`/`day` (not read from the cached
+    /// [`Self::weekday`] field), so it stays correct even if a caller
+    /// mutates those fields directly after construction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `year`/`month`/`day` don't describe a valid date.
+    #[must_use]
+    pub fn weekday(&self) -> Weekday {
+        weekday_for_ymdhms(self.year, self.month, self.day, self.hour, self.minute, self.second)
+            .expect("fields do not describe a valid date")
+    }
+
+    /// Name of the month in English, against the local wall-clock date.
+    #[must_use]
+    pub fn monthname(&self) -> &'static str {
+        super::MONAME[(self.month - 1) as usize]
+    }
+
+    /// Name of the day of the week in English, against the local
+    /// wall-clock date.
+    #[must_use]
+    pub fn dayname(&self) -> &'static str {
+        super::DAYNAME[self.weekday() as usize]
+    }
+}
+
+impl FromStr for FixedOffsetDateTime {
+    type Err = &'static str;
+
+    fn from_str(datetime: &str) -> Result<Self, Self::Err> {
+        iso_string_to_fixedoffsetdatetime(datetime)
+    }
+}
+
+impl TryFrom<&str> for FixedOffsetDateTime {
+    type Error = &'static str;
+
+    fn try_from(datetime: &str) -> Result<Self, Self::Error> {
+        datetime.parse()
+    }
+}
+
+impl fmt::Display for FixedOffsetDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:0>4}-{:0>2}-{:0>2}T{:0>2}:{:0>2}:{:0>2}{}",
+            self.year,
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second,
+            offset_seconds_to_string(self.offset_seconds),
+        )
+    }
+}
+
+/// `serde` support, gated behind the `serde` feature.
+///
+/// [`UTCDateTime`] and [`LocalDateTime`] (de)serialize to an ISO 8601 /
+/// RFC 3339 string (e.g. `"1968-02-27T09:10:00Z"`), reusing their
+/// existing `Display`/parsing paths, rather than exposing their raw
+/// fields. This lets moon-phase results be embedded directly in JSON
+/// APIs and config files without callers hand-rolling string
+/// conversions.
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::{iso_string_to_local_datetime, local_datetime_to_iso_string};
+    use super::{LocalDateTime, UTCDateTime};
+    use serde::de::{self, Visitor};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::fmt;
+
+    impl Serialize for UTCDateTime {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&self.to_string())
+        }
+    }
+
+    struct UTCDateTimeVisitor;
+
+    impl Visitor<'_> for UTCDateTimeVisitor {
+        type Value = UTCDateTime;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("an ISO 8601 / RFC 3339 datetime string")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            v.parse().map_err(de::Error::custom)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for UTCDateTime {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_str(UTCDateTimeVisitor)
+        }
+    }
+
+    impl Serialize for LocalDateTime {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&local_datetime_to_iso_string(self))
+        }
+    }
+
+    struct LocalDateTimeVisitor;
+
+    impl Visitor<'_> for LocalDateTimeVisitor {
+        type Value = LocalDateTime;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("an ISO 8601 datetime string, in local time")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            iso_string_to_local_datetime(v).map_err(de::Error::custom)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for LocalDateTime {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_str(LocalDateTimeVisitor)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn utcdatetime_serializes_to_iso_string() {
+            let dt = UTCDateTime::from_ymdhms(1968, 2, 27, 9, 10, 0);
+
+            assert_eq!(
+                serde_json::to_string(&dt).unwrap(),
+                r#""1968-02-27T09:10:00Z""#
+            );
+        }
+
+        #[test]
+        fn utcdatetime_round_trips_through_serde() {
+            let dt = UTCDateTime::from_ymdhms(1968, 2, 27, 9, 10, 0);
+
+            let json = serde_json::to_string(&dt).unwrap();
+            let round_tripped: UTCDateTime = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(round_tripped, dt);
+        }
+
+        #[test]
+        fn utcdatetime_deserialize_rejects_invalid_string() {
+            let result: Result<UTCDateTime, _> = serde_json::from_str(r#""not a date""#);
+
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn localdatetime_round_trips_through_serde() {
+            let dt = LocalDateTime {
+                year: 1968,
+                month: 2,
+                day: 27,
+                weekday: 2,
+                hour: 9,
+                minute: 10,
+                second: 0,
+            };
+
+            let json = serde_json::to_string(&dt).unwrap();
+            let round_tripped: LocalDateTime = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(round_tripped, dt);
+        }
+    }
+}
+
+/// Alternate `serde` encoding of [`UTCDateTime`] as a Unix timestamp,
+/// for use with `#[serde(with = "moontool::moon::timestamp")]` instead
+/// of the default ISO 8601 string.
+#[cfg(feature = "serde")]
+pub mod timestamp {
+    use super::UTCDateTime;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// # Errors
+    ///
+    /// Errors if `datetime` is out of `UTCDateTime::to_timestamp`'s
+    /// representable range.
+    pub fn serialize<S: Serializer>(
+        datetime: &UTCDateTime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let timestamp = datetime.to_timestamp().map_err(serde::ser::Error::custom)?;
+        timestamp.serialize(serializer)
+    }
+
+    /// # Errors
+    ///
+    /// Errors if the deserialized integer isn't a valid timestamp.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<UTCDateTime, D::Error> {
+        let timestamp = i64::deserialize(deserializer)?;
+        UTCDateTime::from_timestamp(timestamp).map_err(serde::de::Error::custom)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::super::UTCDateTime;
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        struct Event {
+            #[serde(with = "super::super::timestamp")]
+            at: UTCDateTime,
+        }
+
+        #[test]
+        fn round_trips_through_unix_timestamp() {
+            let event = Event {
+                at: UTCDateTime::from_ymdhms(2024, 4, 30, 18, 21, 42),
+            };
+
+            let json = serde_json::to_string(&event).unwrap();
+            assert_eq!(json, r#"{"at":1714501302}"#);
+
+            let round_tripped: Event = serde_json::from_str(&json).unwrap();
+            assert_eq!(round_tripped.at, event.at);
+        }
+    }
+}
+
+/// Alternate `serde` encoding of [`UTCDateTime`] as a Julian date, for
+/// use with `#[serde(with = "moontool::moon::julian_date")]` instead of
+/// the default ISO 8601 string.
+#[cfg(feature = "serde")]
+pub mod julian_date {
+    use super::UTCDateTime;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        datetime: &UTCDateTime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        datetime.to_julian_date().serialize(serializer)
+    }
+
+    /// # Errors
+    ///
+    /// Errors if the deserialized value isn't a valid `f64`.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<UTCDateTime, D::Error> {
+        let julian_date = f64::deserialize(deserializer)?;
+        Ok(UTCDateTime::from_julian_date(julian_date))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::super::UTCDateTime;
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        struct Event {
+            #[serde(with = "super::super::julian_date")]
+            at: UTCDateTime,
+        }
+
+        #[test]
+        fn round_trips_through_julian_date() {
+            let event = Event {
+                at: UTCDateTime::from_ymdhms(2024, 4, 30, 18, 21, 42),
+            };
+
+            let json = serde_json::to_string(&event).unwrap();
+            let round_tripped: Event = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(round_tripped.at, event.at);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! assert_almost_eq {
+        ($a:expr, $b:expr) => {
+            assert!(($a - $b).abs() < f64::EPSILON, "{} != {}", $a, $b);
+        };
+    }
+
+    // Date/time utils
+
+    #[test]
+    fn utcdatetime_to_timestamp_regular() {
+        let t =
+            utcdatetime_to_timestamp(&UTCDateTime::from_ymdhms(2024, 4, 30, 18, 21, 42)).unwrap();
+
+        assert_eq!(t, 1_714_501_302);
+    }
+
+    #[test]
+    fn utcdatetime_to_timestamp_zero() {
+        let t = utcdatetime_to_timestamp(&UTCDateTime::from_ymdhms(1970, 1, 1, 0, 0, 0)).unwrap();
+
+        assert_eq!(t, 0);
+    }
+
+    #[test]
+    fn utcdatetime_to_timestamp_negative() {
+        let t = utcdatetime_to_timestamp(&UTCDateTime::from_ymdhms(1940, 10, 13, 0, 0, 0)).unwrap();
+
+        assert_eq!(t, -922_060_800);
+    }
+
+    #[test]
+    fn timestamp_to_utcdatetime_regular() {
+        let dt = timestamp_to_utcdatetime(1_714_501_302).unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymddhms(2024, 4, 30, 2, 18, 21, 42));
+    }
+
+    #[test]
+    fn timestamp_to_utcdatetime_zero() {
+        let dt = timestamp_to_utcdatetime(0).unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymddhms(1970, 1, 1, 4, 0, 0, 0));
+    }
+
+    #[test]
+    fn timestamp_to_utcdatetime_negative() {
+        let dt = timestamp_to_utcdatetime(-922_060_800).unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymddhms(1940, 10, 13, 0, 0, 0, 0));
+    }
+
+    #[test]
+    fn timestamp_to_utcdatetime_bad_timestamp() {
+        let dt = timestamp_to_utcdatetime(i64::MAX);
+
+        assert!(dt.is_err());
+    }
+
+    #[test]
+    fn iso_datetime_string_to_utcdatetime_from_datetime_utc() {
+        let dt = iso_datetime_string_to_utcdatetime("1964-12-20T04:35:00Z").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymddhms(1964, 12, 20, 0, 4, 35, 0));
+    }
+
+    #[test]
+    fn iso_datetime_string_to_utcdatetime_from_datetime_utc_lowercase() {
+        let dt = iso_datetime_string_to_utcdatetime("1964-12-20t04:35:00z").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymddhms(1964, 12, 20, 0, 4, 35, 0));
+    }
+
+    #[test]
+    fn iso_datetime_string_to_utcdatetime_from_datetime_implicit_utc() {
+        let dt = iso_datetime_string_to_utcdatetime("1964-12-20T04:35:00").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymddhms(1964, 12, 20, 0, 4, 35, 0));
+    }
+
+    #[test]
+    fn iso_datetime_string_to_utcdatetime_from_datetime_offset() {
+        let dt = iso_datetime_string_to_utcdatetime("1964-12-20T05:35:00+01:00").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymddhms(1964, 12, 20, 0, 4, 35, 0));
+    }
+
+    #[test]
+    fn iso_datetime_string_to_utcdatetime_from_datetime_error_invalid_string() {
+        let dt = iso_datetime_string_to_utcdatetime("1964-12-20T05-35-00");
+
+        assert!(dt.is_err());
+    }
+
+    #[test]
+    fn iso_datetime_string_to_utcdatetime_from_date() {
+        let d = iso_datetime_string_to_utcdatetime("1938-07-15").unwrap();
+
+        assert_eq!(d, UTCDateTime::from_ymddhms(1938, 7, 15, 5, 0, 0, 0));
+    }
+
+    #[test]
+    fn iso_datetime_string_to_utcdatetime_from_date_error_invalid_string() {
+        let d = iso_datetime_string_to_utcdatetime("1938:07:15");
+
+        assert!(d.is_err());
+    }
+
+    #[test]
+    fn iso_datetime_string_to_utcdatetime_fractional_second_1_digit() {
+        let dt = iso_datetime_string_to_utcdatetime("2015-02-18T23:59:30.2Z").unwrap();
+
+        assert_eq!(
+            dt,
+            UTCDateTime {
+                nanosecond: 200_000_000,
+                ..UTCDateTime::from_ymddhms(2015, 2, 18, 3, 23, 59, 30)
+            }
+        );
+    }
+
+    #[test]
+    fn iso_datetime_string_to_utcdatetime_fractional_second_3_digits() {
+        let dt = iso_datetime_string_to_utcdatetime("2015-02-18T23:59:30.234Z").unwrap();
+
+        assert_eq!(
+            dt,
+            UTCDateTime {
+                nanosecond: 234_000_000,
+                ..UTCDateTime::from_ymddhms(2015, 2, 18, 3, 23, 59, 30)
+            }
+        );
+    }
+
+    #[test]
+    fn iso_datetime_string_to_utcdatetime_fractional_second_6_digits() {
+        let dt = iso_datetime_string_to_utcdatetime("2015-02-18T23:59:30.234567Z").unwrap();
+
+        assert_eq!(
+            dt,
+            UTCDateTime {
+                nanosecond: 234_567_000,
+                ..UTCDateTime::from_ymddhms(2015, 2, 18, 3, 23, 59, 30)
+            }
+        );
+    }
+
+    #[test]
+    fn iso_datetime_string_to_utcdatetime_fractional_second_9_digits() {
+        let dt = iso_datetime_string_to_utcdatetime("2015-02-18T23:59:30.234567890Z").unwrap();
+
+        assert_eq!(
+            dt,
+            UTCDateTime {
+                nanosecond: 234_567_890,
+                ..UTCDateTime::from_ymddhms(2015, 2, 18, 3, 23, 59, 30)
+            }
+        );
+    }
+
+    #[test]
+    fn iso_datetime_string_to_utcdatetime_leap_second_utc() {
+        let dt = iso_datetime_string_to_utcdatetime("2015-02-18T23:59:60Z").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymddhms(2015, 2, 18, 3, 23, 59, 59));
+    }
+
+    #[test]
+    fn iso_datetime_string_to_utcdatetime_leap_second_with_numeric_offset() {
+        let dt = iso_datetime_string_to_utcdatetime("2015-02-18T23:59:60.234567+05:00").unwrap();
+
+        assert_eq!(
+            dt,
+            UTCDateTime {
+                nanosecond: 234_567_000,
+                ..UTCDateTime::from_ymddhms(2015, 2, 18, 3, 18, 59, 59)
+            }
+        );
+    }
+
+    #[test]
+    fn normalize_leap_second_keeps_fractional_seconds() {
+        assert_eq!(
+            normalize_leap_second("2015-02-18T23:59:30.234567Z"),
+            "2015-02-18T23:59:30.234567Z"
+        );
+    }
+
+    #[test]
+    fn normalize_leap_second_folds_leap_second_with_fraction() {
+        assert_eq!(
+            normalize_leap_second("2015-02-18T23:59:60.234567+05:00"),
+            "2015-02-18T23:59:59.234567+05:00"
+        );
+    }
+
+    #[test]
+    fn normalize_leap_second_date_only_is_unchanged() {
+        assert_eq!(normalize_leap_second("2015-02-18"), "2015-02-18");
+    }
+
+    #[test]
+    fn rfc2822_string_to_utcdatetime_numeric_offset() {
+        let dt = rfc2822_string_to_utcdatetime("Sun, 20 Jul 1969 20:17:40 +0000").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymddhms(1969, 7, 20, 0, 20, 17, 40));
+    }
+
+    #[test]
+    fn rfc2822_string_to_utcdatetime_non_utc_offset() {
+        let dt = rfc2822_string_to_utcdatetime("Sun, 20 Jul 1969 16:17:40 -0400").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymddhms(1969, 7, 20, 0, 20, 17, 40));
+    }
+
+    #[test]
+    fn rfc2822_string_to_utcdatetime_error_invalid_string() {
+        let dt = rfc2822_string_to_utcdatetime("1969-07-20T20:17:40Z");
+
+        assert!(dt.is_err());
+    }
+
+    #[test]
+    fn http_date_string_to_utcdatetime_regular() {
+        let dt = http_date_string_to_utcdatetime("Sun, 20 Jul 1969 20:17:40 GMT").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymddhms(1969, 7, 20, 0, 20, 17, 40));
+    }
+
+    #[test]
+    fn http_date_string_to_utcdatetime_error_invalid_string() {
+        let dt = http_date_string_to_utcdatetime("Sun, 20 Jul 1969 20:17:40 +0000");
+
+        assert!(dt.is_err());
+    }
+
+    #[test]
+    fn utcdatetime_from_httpdate_imf_fixdate() {
+        let dt = UTCDateTime::from_httpdate("Sun, 06 Nov 1994 08:49:37 GMT").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(1994, 11, 6, 8, 49, 37));
+    }
+
+    #[test]
+    fn utcdatetime_from_httpdate_rfc850() {
+        let dt = UTCDateTime::from_httpdate("Sunday, 06-Nov-94 08:49:37 GMT").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(1994, 11, 6, 8, 49, 37));
+    }
+
+    #[test]
+    fn utcdatetime_from_httpdate_rfc850_windows_two_digit_year_to_1900s() {
+        let dt = UTCDateTime::from_httpdate("Sunday, 06-Nov-94 08:49:37 GMT").unwrap();
+
+        assert_eq!(dt.year, 1994);
+    }
+
+    #[test]
+    fn utcdatetime_from_httpdate_rfc850_windows_two_digit_year_to_2000s() {
+        let dt = UTCDateTime::from_httpdate("Wednesday, 06-Nov-24 08:49:37 GMT").unwrap();
+
+        assert_eq!(dt.year, 2024);
+    }
+
+    #[test]
+    fn utcdatetime_from_httpdate_asctime() {
+        let dt = UTCDateTime::from_httpdate("Sun Nov  6 08:49:37 1994").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(1994, 11, 6, 8, 49, 37));
+    }
+
+    #[test]
+    fn utcdatetime_from_httpdate_asctime_two_digit_day() {
+        let dt = UTCDateTime::from_httpdate("Tue Nov 22 08:49:37 1994").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(1994, 11, 22, 8, 49, 37));
+    }
+
+    #[test]
+    fn utcdatetime_from_httpdate_rejects_missing_weekday() {
+        let dt = UTCDateTime::from_httpdate("1 Dec 2001 10:23:57 GMT");
+
+        assert!(dt.is_err());
+    }
+
+    #[test]
+    fn utcdatetime_from_httpdate_rejects_invalid_string() {
+        let dt = UTCDateTime::from_httpdate("not a date");
+
+        assert!(dt.is_err());
+    }
+
+    #[test]
+    fn from_rfc2822_string_with_numeric_offset() {
+        let dt = UTCDateTime::from_rfc2822_string("Sun, 20 Jul 1969 20:17:40 +0000").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymddhms(1969, 7, 20, 0, 20, 17, 40));
+    }
+
+    #[test]
+    fn from_rfc2822_string_with_http_date() {
+        let dt = UTCDateTime::from_rfc2822_string("Sun, 20 Jul 1969 20:17:40 GMT").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymddhms(1969, 7, 20, 0, 20, 17, 40));
+    }
+
+    #[test]
+    fn from_rfc2822_string_error_invalid_string() {
+        let dt = UTCDateTime::from_rfc2822_string("invalid");
+
+        assert!(dt.is_err());
+    }
+
+    #[test]
+    fn from_rfc2822_with_day_of_week_and_numeric_offset() {
+        let dt = UTCDateTime::from_rfc2822("Fri, 21 Nov 1997 09:55:06 -0600").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(1997, 11, 21, 15, 55, 6));
+    }
+
+    #[test]
+    fn from_rfc2822_without_day_of_week() {
+        let dt = UTCDateTime::from_rfc2822("21 Nov 1997 09:55:06 -0600").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(1997, 11, 21, 15, 55, 6));
+    }
+
+    #[test]
+    fn from_rfc2822_obsolete_zone_abbreviations() {
+        assert_eq!(
+            UTCDateTime::from_rfc2822("21 Nov 1997 09:55:06 EST").unwrap(),
+            UTCDateTime::from_ymdhms(1997, 11, 21, 14, 55, 6)
+        );
+        assert_eq!(
+            UTCDateTime::from_rfc2822("21 Nov 1997 09:55:06 PDT").unwrap(),
+            UTCDateTime::from_ymdhms(1997, 11, 21, 16, 55, 6)
+        );
+        assert_eq!(
+            UTCDateTime::from_rfc2822("21 Nov 1997 09:55:06 UT").unwrap(),
+            UTCDateTime::from_ymdhms(1997, 11, 21, 9, 55, 6)
+        );
+    }
+
+    #[test]
+    fn from_rfc2822_tolerates_whitespace_runs() {
+        let dt = UTCDateTime::from_rfc2822("Fri,  21   Nov  1997  09:55:06  -0600").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(1997, 11, 21, 15, 55, 6));
+    }
+
+    #[test]
+    fn from_rfc2822_rejects_parenthetical_comment() {
+        let dt = UTCDateTime::from_rfc2822("Fri, 21 Nov 1997 09(comment):55:06 -0600");
+
+        assert!(dt.is_err());
+    }
+
+    #[test]
+    fn from_rfc2822_invalid_string() {
+        let dt = UTCDateTime::from_rfc2822("not a date");
+
+        assert!(dt.is_err());
+    }
+
+    #[test]
+    fn to_rfc2822_roundtrip() {
+        let dt = UTCDateTime::from_ymdhms(1997, 11, 21, 15, 55, 6);
+
+        assert_eq!(dt.to_rfc2822(), "Fri, 21 Nov 1997 15:55:06 +0000");
+        assert_eq!(UTCDateTime::from_rfc2822(&dt.to_rfc2822()).unwrap(), dt);
+    }
+
+    #[test]
+    fn weekday_for_ymdhms_regular() {
+        assert_eq!(weekday_for_ymdhms(2024, 5, 13, 20, 47, 23).unwrap(), Weekday::Monday);
+        assert_eq!(weekday_for_ymdhms(2024, 5, 14, 20, 47, 23).unwrap(), Weekday::Tuesday);
+        assert_eq!(weekday_for_ymdhms(2024, 5, 15, 20, 47, 23).unwrap(), Weekday::Wednesday);
+        assert_eq!(weekday_for_ymdhms(2024, 5, 16, 20, 47, 23).unwrap(), Weekday::Thursday);
+        assert_eq!(weekday_for_ymdhms(2024, 5, 17, 20, 47, 23).unwrap(), Weekday::Friday);
+        assert_eq!(weekday_for_ymdhms(2024, 5, 18, 20, 47, 23).unwrap(), Weekday::Saturday);
+        assert_eq!(weekday_for_ymdhms(2024, 5, 19, 20, 47, 23).unwrap(), Weekday::Sunday);
+    }
+
+    #[test]
+    fn weekday_for_ymdhms_error() {
+        let weekday = weekday_for_ymdhms(2024, 5, 99, 20, 47, 23);
+
+        assert!(weekday.is_err());
+    }
+
+    #[test]
+    fn weekday_number_days_from_sunday() {
+        assert_eq!(Weekday::Sunday.number_days_from_sunday(), 0);
+        assert_eq!(Weekday::Monday.number_days_from_sunday(), 1);
+        assert_eq!(Weekday::Saturday.number_days_from_sunday(), 6);
+    }
+
+    #[test]
+    fn weekday_succ_and_pred_wrap_around() {
+        assert_eq!(Weekday::Saturday.succ(), Weekday::Sunday);
+        assert_eq!(Weekday::Sunday.pred(), Weekday::Saturday);
+        assert_eq!(Weekday::Monday.succ(), Weekday::Tuesday);
+        assert_eq!(Weekday::Tuesday.pred(), Weekday::Monday);
+    }
+
+    #[test]
+    fn weekday_try_from_u32_round_trips() {
+        for (n, weekday) in [
+            (0, Weekday::Sunday),
+            (1, Weekday::Monday),
+            (2, Weekday::Tuesday),
+            (3, Weekday::Wednesday),
+            (4, Weekday::Thursday),
+            (5, Weekday::Friday),
+            (6, Weekday::Saturday),
+        ] {
+            assert_eq!(Weekday::try_from(n).unwrap(), weekday);
+            assert_eq!(u32::from(weekday), n);
+        }
+        assert!(Weekday::try_from(7).is_err());
+        assert!(Weekday::try_from(99).is_err());
+    }
+
+    #[test]
+    fn utcdatetime_weekday_method_matches_field() {
+        let dt = UTCDateTime::from_ymdhms(2024, 5, 13, 20, 47, 23);
+
+        assert_eq!(dt.weekday(), Weekday::Monday);
+        assert_eq!(u32::from(dt.weekday()), dt.weekday);
+    }
+
+    #[test]
+    fn utcdatetime_weekday_method_recomputes_after_field_mutation() {
+        // weekday() must not trust the cached `weekday` field: it's
+        // recomputed from year/month/day, so mutating those directly
+        // (a pattern this crate uses, e.g. in `fmt_phase_time`'s tests)
+        // keeps weekday() correct.
+        let mut dt = UTCDateTime::from_ymdhms(2024, 5, 13, 20, 47, 23); // Monday.
+        dt.day = 14; // Tuesday, but `weekday` field still says Monday.
+
+        assert_eq!(dt.weekday(), Weekday::Tuesday);
+    }
+
+    #[test]
+    #[should_panic(expected = "valid date")]
+    fn utcdatetime_weekday_method_panics_on_invalid_date() {
+        let dt = UTCDateTime::from_ymddhms(2024, 5, 99, 99, 20, 47, 23);
+
+        let _ = dt.weekday();
+    }
+
+    #[test]
+    fn utcdatetime_to_offsetdatetime_regular() {
+        let odt =
+            utcdatetime_to_offsetdatetime(&UTCDateTime::from_ymddhms(1938, 7, 15, 5, 0, 0, 0))
+                .unwrap();
+
+        assert_eq!(
+            odt,
+            time::OffsetDateTime::new_utc(
+                time::Date::from_calendar_date(1938, time::Month::July, 15).unwrap(),
+                time::Time::from_hms(0, 0, 0).unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn utcdatetime_to_offsetdatetime_bad_month() {
+        let odt =
+            utcdatetime_to_offsetdatetime(&UTCDateTime::from_ymddhms(1938, 9999, 15, 5, 0, 0, 0));
+
+        assert!(odt.is_err());
+    }
+
+    #[test]
+    fn utcdatetime_to_offsetdatetime_bad_date() {
+        let odt =
+            utcdatetime_to_offsetdatetime(&UTCDateTime::from_ymddhms(1938, 7, 255, 5, 0, 0, 0));
+
+        assert!(odt.is_err());
+    }
+
+    #[test]
+    fn utcdatetime_to_offsetdatetime_bad_time() {
+        let odt =
+            utcdatetime_to_offsetdatetime(&UTCDateTime::from_ymddhms(1938, 7, 15, 5, 255, 0, 0));
+
+        assert!(odt.is_err());
+    }
+
+    #[test]
+    fn offsetdatetime_to_utcdatetime_regular() {
+        let dt = offsetdatetime_to_utcdatetime(&time::OffsetDateTime::new_utc(
+            time::Date::from_calendar_date(1938, time::Month::July, 15).unwrap(),
+            time::Time::from_hms(0, 0, 0).unwrap(),
+        ));
+
+        assert_eq!(dt, UTCDateTime::from_ymddhms(1938, 7, 15, 5, 0, 0, 0));
+    }
+
+    // UTCDateTime
+
+    #[test]
+    fn every_way_of_creating_utcdatetime_gives_same_result() {
+        let a = UTCDateTime {
+            year: 1968,
+            month: 2,
+            day: 27,
+            weekday: 2,
+            hour: 9,
+            minute: 10,
+            second: 0,
+            nanosecond: 0,
+        };
+        let b = UTCDateTime::from_ymdhms(1968, 2, 27, 9, 10, 0);
+        let c = UTCDateTime::from_ymddhms(1968, 2, 27, 2, 9, 10, 0);
+        let d = "1968-02-27T09:10:00Z".parse::<UTCDateTime>().unwrap();
+        let e = UTCDateTime::from_iso_string("1968-02-27T09:10:00Z").unwrap();
+        let f = UTCDateTime::try_from("1968-02-27T09:10:00Z").unwrap();
+        let g = UTCDateTime::from(time::OffsetDateTime::new_utc(
+            time::Date::from_calendar_date(1968, time::Month::February, 27).unwrap(),
+            time::Time::from_hms(9, 10, 0).unwrap(),
+        ));
+        let h = UTCDateTime::from_timestamp(-58_200_600).unwrap();
+        let i = UTCDateTime::from_julian_date(2_439_913.881_944_444_5);
+
+        assert!([b, c, d, e, f, g, h, i].iter().all(|x| *x == a));
+    }
+
+    #[test]
+    fn utcdatetime_try_from_ymdhms_valid() {
+        let dt = UTCDateTime::try_from_ymdhms(1968, 2, 27, 9, 10, 0).unwrap();
+        assert_eq!(dt, UTCDateTime::from_ymdhms(1968, 2, 27, 9, 10, 0));
+    }
+
+    #[test]
+    fn utcdatetime_try_from_ymdhms_leap_day_on_leap_year() {
+        assert!(UTCDateTime::try_from_ymdhms(2024, 2, 29, 0, 0, 0).is_ok());
+    }
+
+    #[test]
+    fn utcdatetime_try_from_ymdhms_leap_day_on_non_leap_year() {
+        assert!(UTCDateTime::try_from_ymdhms(2023, 2, 29, 0, 0, 0).is_err());
+    }
+
+    #[test]
+    fn utcdatetime_try_from_ymdhms_leap_second() {
+        assert!(UTCDateTime::try_from_ymdhms(2015, 6, 30, 23, 59, 60).is_ok());
+    }
+
+    #[test]
+    fn utcdatetime_try_from_ymdhms_invalid_month() {
+        assert!(UTCDateTime::try_from_ymdhms(2024, 13, 1, 0, 0, 0).is_err());
+        assert!(UTCDateTime::try_from_ymdhms(2024, 0, 1, 0, 0, 0).is_err());
+    }
+
+    #[test]
+    fn utcdatetime_try_from_ymdhms_invalid_day() {
+        assert!(UTCDateTime::try_from_ymdhms(2024, 4, 31, 0, 0, 0).is_err());
+        assert!(UTCDateTime::try_from_ymdhms(2024, 1, 0, 0, 0, 0).is_err());
+    }
+
+    #[test]
+    fn utcdatetime_try_from_ymdhms_invalid_hour() {
+        assert!(UTCDateTime::try_from_ymdhms(2024, 1, 1, 24, 0, 0).is_err());
+    }
+
+    #[test]
+    fn utcdatetime_try_from_ymdhms_invalid_minute() {
+        assert!(UTCDateTime::try_from_ymdhms(2024, 1, 1, 0, 60, 0).is_err());
+    }
+
+    #[test]
+    fn utcdatetime_try_from_ymdhms_invalid_second() {
+        assert!(UTCDateTime::try_from_ymdhms(2024, 1, 1, 0, 0, 61).is_err());
+    }
+
+    #[test]
+    fn parse_time_travel_string_full_hms() {
+        let dt = parse_time_travel_string("14:21:05 4 May 2024").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(2024, 5, 4, 14, 21, 5));
+    }
+
+    #[test]
+    fn parse_time_travel_string_hour_minute() {
+        let dt = parse_time_travel_string("14:21 4 May 2024").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(2024, 5, 4, 14, 21, 0));
+    }
+
+    #[test]
+    fn parse_time_travel_string_compact_hhmm() {
+        let dt = parse_time_travel_string("1421 4 May 2024").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(2024, 5, 4, 14, 21, 0));
+    }
+
+    #[test]
+    fn parse_time_travel_string_day_month_year() {
+        let dt = parse_time_travel_string("4 May 2024").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(2024, 5, 4, 0, 0, 0));
+    }
+
+    #[test]
+    fn parse_time_travel_string_month_year() {
+        let dt = parse_time_travel_string("April 1990").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(1990, 4, 1, 0, 0, 0));
+    }
+
+    #[test]
+    fn parse_time_travel_string_bare_year() {
+        let dt = parse_time_travel_string("1977").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(1977, 1, 1, 0, 0, 0));
+    }
+
+    #[test]
+    fn parse_time_travel_string_month_abbreviation() {
+        let dt = parse_time_travel_string("4 Apr 2024").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(2024, 4, 4, 0, 0, 0));
+    }
+
+    #[test]
+    fn parse_time_travel_string_month_name_case_insensitive() {
+        let dt = parse_time_travel_string("4 january 2024").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(2024, 1, 4, 0, 0, 0));
+    }
+
+    #[test]
+    fn parse_time_travel_string_invalid_month_name() {
+        assert!(parse_time_travel_string("4 Smarch 2024").is_err());
+    }
+
+    #[test]
+    fn parse_time_travel_string_invalid_token_count() {
+        assert!(parse_time_travel_string("14:21 4 May 2024 extra").is_err());
+        assert!(parse_time_travel_string("").is_err());
+    }
+
+    #[test]
+    fn utcdatetime_from_time_travel_string() {
+        let dt = UTCDateTime::from_time_travel_string("April 1990").unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(1990, 4, 1, 0, 0, 0));
+    }
+
+    #[test]
+    fn utcdatetime_from_iso_string_date() {
+        let a = UTCDateTime {
+            year: 2024,
+            month: 6,
+            day: 14,
+            weekday: 5,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            nanosecond: 0,
+        };
+        let b = "2024-06-14".parse::<UTCDateTime>().unwrap();
+
+        assert!(std::iter::once(&b).all(|x| *x == a));
+    }
+
+    #[test]
+    fn utcdatetime_from_iso_string_datetime() {
+        let a = UTCDateTime {
+            year: 2024,
+            month: 6,
+            day: 14,
+            weekday: 5,
+            hour: 21,
+            minute: 21,
+            second: 0,
+            nanosecond: 0,
+        };
+        let b = "2024-06-14T21:21:00".parse::<UTCDateTime>().unwrap();
+        let c = "2024-06-14T21:21:00Z".parse::<UTCDateTime>().unwrap();
+        let d = "2024-06-14T23:21:00+02:00".parse::<UTCDateTime>().unwrap();
+
+        assert!([b, c, d].iter().all(|x| *x == a));
+    }
+
+    #[test]
+    fn utcdatetime_try_from_timestamp_positive() {
+        let dt = UTCDateTime::from_timestamp(966_600_000).unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymddhms(2000, 8, 18, 5, 12, 0, 0));
+    }
+
+    #[test]
+    fn utcdatetime_try_from_timestamp_zero() {
+        let dt = UTCDateTime::from_timestamp(0).unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymddhms(1970, 1, 1, 4, 0, 0, 0));
+    }
+
+    #[test]
+    fn utcdatetime_try_from_timestamp_negative() {
+        let dt = UTCDateTime::from_timestamp(-58_200_600).unwrap();
+
+        assert_eq!(dt, UTCDateTime::from_ymddhms(1968, 2, 27, 2, 9, 10, 0));
+    }
+
+    #[test]
+    fn utcdatetime_to_timestamp_positive() {
+        let dt = UTCDateTime::from_ymddhms(2000, 8, 18, 5, 12, 0, 0);
+
+        assert_eq!(dt.to_timestamp().unwrap(), 966_600_000);
+    }
+
+    #[test]
+    fn utcdatetime_to_timestamp_zero_() {
+        let dt = UTCDateTime::from_ymddhms(1970, 1, 1, 4, 0, 0, 0);
+
+        assert_eq!(dt.to_timestamp().unwrap(), 0);
+    }
+
+    #[test]
+    fn utcdatetime_to_timestamp_negative_() {
+        let dt = UTCDateTime::from_ymddhms(1968, 2, 27, 2, 9, 10, 0);
+
+        assert_eq!(dt.to_timestamp().unwrap(), -58_200_600);
+    }
+
+    #[test]
+    fn utcdatetime_from_julian_date_regular() {
+        let dt = UTCDateTime::from_julian_date(2_460_473.196_55);
+
+        assert_eq!(dt, UTCDateTime::from_ymdhms(2024, 6, 11, 16, 43, 2));
+    }
+
+    #[test]
+    fn utcdatetime_from_julian_date_zero() {
+        let dt = UTCDateTime::from_julian_date(0.0);
+
+        assert_eq!(dt, UTCDateTime::from_ymddhms(-4712, 1, 1, 1, 12, 0, 0));
+    }
+
+    #[test]
+    fn utcdatetime_to_julian_date_regular() {
+        let dt = UTCDateTime::from_ymdhms(2024, 6, 11, 16, 43, 2);
+
+        assert_almost_eq!(dt.to_julian_date(), 2_460_473.196_550_925_7);
+    }
+
+    #[test]
+    fn utcdatetime_to_julian_date_zero() {
+        let dt = UTCDateTime::from_ymddhms(-4712, 1, 1, 1, 12, 0, 0);
+
+        assert_almost_eq!(dt.to_julian_date(), 0.0);
+    }
+
+    #[test]
+    fn utcdatetime_to_julian_date_carries_nanosecond_fraction() {
+        let dt = UTCDateTime {
+            nanosecond: 500_000_000,
+            ..UTCDateTime::from_ymdhms(2024, 6, 11, 16, 43, 2)
+        };
+
+        assert_almost_eq!(dt.to_julian_date(), 2_460_473.196_550_925_7 + 0.5 / 86400.0);
+    }
+
+    #[test]
+    fn utcdatetime_to_civil_julian_date_regular() {
+        let dt = UTCDateTime::from_ymdhms(2024, 6, 11, 16, 43, 2);
+
+        assert_almost_eq!(dt.to_civil_julian_date(), 2_460_473.696_550_925_7);
+    }
+
+    #[test]
+    fn utcdatetime_to_civil_julian_date_zero() {
+        let dt = UTCDateTime::from_ymddhms(-4712, 1, 1, 1, 0, 0, 0);
+
+        assert_almost_eq!(dt.to_civil_julian_date(), 0.0);
+    }
+
+    #[test]
+    fn utcdatetime_ordinal() {
+        let dt = UTCDateTime::from_ymdhms(2024, 6, 14, 0, 0, 0);
+
+        assert_eq!(dt.ordinal().unwrap(), 166);
+    }
+
+    #[test]
+    fn utcdatetime_iso_weekday() {
+        // Friday.
+        let dt = UTCDateTime::from_ymdhms(2024, 6, 14, 0, 0, 0);
+
+        assert_eq!(dt.iso_weekday().unwrap(), 5);
+    }
+
+    #[test]
+    fn utcdatetime_iso_week() {
+        let dt = UTCDateTime::from_ymdhms(2024, 6, 14, 0, 0, 0);
+
+        assert_eq!(dt.iso_week().unwrap(), (2024, 24));
+    }
+
+    #[test]
+    fn utcdatetime_iso_week_crosses_into_next_iso_year() {
+        let dt = UTCDateTime::from_ymdhms(2024, 12, 31, 0, 0, 0);
+
+        assert_eq!(dt.iso_week().unwrap(), (2025, 1));
+    }
+
+    #[test]
+    fn utcdatetime_add_duration() {
+        let dt = UTCDateTime::from_ymdhms(2024, 6, 14, 0, 0, 0);
+
+        assert_eq!(
+            dt + std::time::Duration::from_secs(86_400),
+            UTCDateTime::from_ymdhms(2024, 6, 15, 0, 0, 0),
+        );
     }
 
     #[test]
-    fn utcdatetime_to_timestamp_negative() {
-        let t = utcdatetime_to_timestamp(&UTCDateTime::from_ymdhms(1940, 10, 13, 0, 0, 0)).unwrap();
+    fn utcdatetime_add_duration_crosses_year_boundary() {
+        let dt = UTCDateTime::from_ymdhms(2024, 12, 31, 23, 0, 0);
 
-        assert_eq!(t, -922_060_800);
+        assert_eq!(
+            dt + std::time::Duration::from_secs(3_600),
+            UTCDateTime::from_ymdhms(2025, 1, 1, 0, 0, 0),
+        );
     }
 
     #[test]
-    fn timestamp_to_utcdatetime_regular() {
-        let dt = timestamp_to_utcdatetime(1_714_501_302).unwrap();
+    fn utcdatetime_sub_duration() {
+        let dt = UTCDateTime::from_ymdhms(2024, 6, 15, 0, 0, 0);
 
-        assert_eq!(dt, UTCDateTime::from_ymddhms(2024, 4, 30, 2, 18, 21, 42));
+        assert_eq!(
+            dt - std::time::Duration::from_secs(86_400),
+            UTCDateTime::from_ymdhms(2024, 6, 14, 0, 0, 0),
+        );
     }
 
     #[test]
-    fn timestamp_to_utcdatetime_zero() {
-        let dt = timestamp_to_utcdatetime(0).unwrap();
+    fn utcdatetime_sub_utcdatetime_yields_elapsed_duration() {
+        let start = UTCDateTime::from_ymdhms(2024, 6, 14, 0, 0, 0);
+        let end = UTCDateTime::from_ymdhms(2024, 6, 15, 12, 0, 0);
 
-        assert_eq!(dt, UTCDateTime::from_ymddhms(1970, 1, 1, 4, 0, 0, 0));
+        assert_eq!(end.clone() - start.clone(), time::Duration::hours(36));
+        assert_eq!(start - end, time::Duration::hours(-36));
     }
 
     #[test]
-    fn timestamp_to_utcdatetime_negative() {
-        let dt = timestamp_to_utcdatetime(-922_060_800).unwrap();
+    fn utcdatetime_sub_utcdatetime_same_instant_is_zero() {
+        let dt = UTCDateTime::from_ymdhms(2024, 6, 14, 0, 0, 0);
 
-        assert_eq!(dt, UTCDateTime::from_ymddhms(1940, 10, 13, 0, 0, 0, 0));
+        assert_eq!(dt.clone() - dt, time::Duration::ZERO);
     }
 
     #[test]
-    fn timestamp_to_utcdatetime_bad_timestamp() {
-        let dt = timestamp_to_utcdatetime(i64::MAX);
+    fn utcdatetime_ord_around_the_epoch() {
+        let before = UTCDateTime::from_ymdhms(1969, 12, 31, 23, 59, 59);
+        let epoch = UTCDateTime::from_ymdhms(1970, 1, 1, 0, 0, 0);
+        let after = UTCDateTime::from_ymdhms(1970, 1, 1, 0, 0, 1);
+
+        assert!(before < epoch);
+        assert!(after > epoch);
+        assert!(before < after);
+    }
 
-        assert!(dt.is_err());
+    #[test]
+    fn utcdatetime_ord_negative_years() {
+        let earlier = UTCDateTime::from_ymdhms(-500, 3, 1, 0, 0, 0);
+        let later = UTCDateTime::from_ymdhms(-500, 3, 2, 0, 0, 0);
+
+        assert!(earlier < later);
+
+        let ancient = UTCDateTime::from_ymdhms(-500, 1, 1, 0, 0, 0);
+        let modern = UTCDateTime::from_ymdhms(1, 1, 1, 0, 0, 0);
+        assert!(ancient < modern);
     }
 
     #[test]
-    fn iso_datetime_string_to_utcdatetime_from_datetime_utc() {
-        let dt = iso_datetime_string_to_utcdatetime("1964-12-20T04:35:00Z").unwrap();
+    fn utcdatetime_ord_ignores_weekday() {
+        let with_sentinel = UTCDateTime::from_ymddhms(2024, 6, 14, 99, 0, 0, 0);
+        let with_real_weekday = UTCDateTime::from_ymdhms(2024, 6, 14, 0, 0, 0);
 
-        assert_eq!(dt, UTCDateTime::from_ymddhms(1964, 12, 20, 0, 4, 35, 0));
+        assert_eq!(with_sentinel.cmp(&with_real_weekday), std::cmp::Ordering::Equal);
     }
 
     #[test]
-    fn iso_datetime_string_to_utcdatetime_from_datetime_utc_lowercase() {
-        let dt = iso_datetime_string_to_utcdatetime("1964-12-20t04:35:00z").unwrap();
+    fn utcdatetime_sort() {
+        let mut dates = vec![
+            UTCDateTime::from_ymdhms(2024, 6, 15, 0, 0, 0),
+            UTCDateTime::from_ymdhms(1970, 1, 1, 0, 0, 0),
+            UTCDateTime::from_ymdhms(2024, 6, 14, 0, 0, 0),
+        ];
+        dates.sort();
 
-        assert_eq!(dt, UTCDateTime::from_ymddhms(1964, 12, 20, 0, 4, 35, 0));
+        assert_eq!(
+            dates,
+            vec![
+                UTCDateTime::from_ymdhms(1970, 1, 1, 0, 0, 0),
+                UTCDateTime::from_ymdhms(2024, 6, 14, 0, 0, 0),
+                UTCDateTime::from_ymdhms(2024, 6, 15, 0, 0, 0),
+            ]
+        );
     }
 
     #[test]
-    fn iso_datetime_string_to_utcdatetime_from_datetime_implicit_utc() {
-        let dt = iso_datetime_string_to_utcdatetime("1964-12-20T04:35:00").unwrap();
+    fn localdatetime_ord_ignores_weekday() {
+        let a = LocalDateTime {
+            year: 2024,
+            month: 6,
+            day: 14,
+            weekday: 99,
+            hour: 12,
+            minute: 0,
+            second: 0,
+        };
+        let b = LocalDateTime {
+            year: 2024,
+            month: 6,
+            day: 14,
+            weekday: 5,
+            hour: 12,
+            minute: 0,
+            second: 0,
+        };
 
-        assert_eq!(dt, UTCDateTime::from_ymddhms(1964, 12, 20, 0, 4, 35, 0));
+        assert_eq!(a.cmp(&b), std::cmp::Ordering::Equal);
     }
 
     #[test]
-    fn iso_datetime_string_to_utcdatetime_from_datetime_offset() {
-        let dt = iso_datetime_string_to_utcdatetime("1964-12-20T05:35:00+01:00").unwrap();
+    fn localdatetime_ordinal() {
+        let dt = LocalDateTime {
+            year: 2024,
+            month: 6,
+            day: 14,
+            weekday: 5,
+            hour: 0,
+            minute: 0,
+            second: 0,
+        };
 
-        assert_eq!(dt, UTCDateTime::from_ymddhms(1964, 12, 20, 0, 4, 35, 0));
+        assert_eq!(dt.ordinal().unwrap(), 166);
     }
 
     #[test]
-    fn iso_datetime_string_to_utcdatetime_from_datetime_error_invalid_string() {
-        let dt = iso_datetime_string_to_utcdatetime("1964-12-20T05-35-00");
+    fn localdatetime_iso_weekday() {
+        // Friday.
+        let dt = LocalDateTime {
+            year: 2024,
+            month: 6,
+            day: 14,
+            weekday: 5,
+            hour: 0,
+            minute: 0,
+            second: 0,
+        };
 
-        assert!(dt.is_err());
+        assert_eq!(dt.iso_weekday().unwrap(), 5);
     }
 
     #[test]
-    fn iso_datetime_string_to_utcdatetime_from_date() {
-        let d = iso_datetime_string_to_utcdatetime("1938-07-15").unwrap();
+    fn localdatetime_iso_week() {
+        let dt = LocalDateTime {
+            year: 2024,
+            month: 6,
+            day: 14,
+            weekday: 5,
+            hour: 0,
+            minute: 0,
+            second: 0,
+        };
 
-        assert_eq!(d, UTCDateTime::from_ymddhms(1938, 7, 15, 5, 0, 0, 0));
+        assert_eq!(dt.iso_week().unwrap(), (2024, 24));
     }
 
     #[test]
-    fn iso_datetime_string_to_utcdatetime_from_date_error_invalid_string() {
-        let d = iso_datetime_string_to_utcdatetime("1938:07:15");
+    fn utcdatetime_parse_invalid_string() {
+        let dt = "Sat. 11 May 2024".parse::<UTCDateTime>();
 
-        assert!(d.is_err());
+        assert!(dt.is_err());
     }
 
     #[test]
-    fn weekday_for_ymdhms_regular() {
-        assert_eq!(weekday_for_ymdhms(2024, 5, 13, 20, 47, 23).unwrap(), 1); // Monday
-        assert_eq!(weekday_for_ymdhms(2024, 5, 14, 20, 47, 23).unwrap(), 2); // Tuesday
-        assert_eq!(weekday_for_ymdhms(2024, 5, 15, 20, 47, 23).unwrap(), 3); // Wednesday
-        assert_eq!(weekday_for_ymdhms(2024, 5, 16, 20, 47, 23).unwrap(), 4); // Thursday
-        assert_eq!(weekday_for_ymdhms(2024, 5, 17, 20, 47, 23).unwrap(), 5); // Friday
-        assert_eq!(weekday_for_ymdhms(2024, 5, 18, 20, 47, 23).unwrap(), 6); // Saturday
-        assert_eq!(weekday_for_ymdhms(2024, 5, 19, 20, 47, 23).unwrap(), 0); // Sunday
+    fn utcdatetime_from_invalid_string() {
+        let dt = UTCDateTime::try_from("Sat. 11 May 2024");
+
+        assert!(dt.is_err());
     }
 
     #[test]
-    fn weekday_for_ymdhms_error() {
-        let weekday = weekday_for_ymdhms(2024, 5, 99, 20, 47, 23);
+    fn utcdatetime_display() {
+        let dt = UTCDateTime::from_ymddhms(1968, 2, 27, 2, 9, 10, 0);
 
-        assert!(weekday.is_err());
+        assert_eq!(dt.to_string(), "1968-02-27T09:10:00Z");
     }
 
     #[test]
-    fn utcdatetime_to_offsetdatetime_regular() {
+    fn utcdatetime_to_offsetdatetime_() {
         let odt =
-            utcdatetime_to_offsetdatetime(&UTCDateTime::from_ymddhms(1938, 7, 15, 5, 0, 0, 0))
+            time::OffsetDateTime::try_from(&UTCDateTime::from_ymddhms(1938, 7, 15, 5, 0, 0, 0))
                 .unwrap();
 
         assert_eq!(
@@ -559,219 +3057,272 @@ mod tests {
         );
     }
 
-    #[test]
-    fn utcdatetime_to_offsetdatetime_bad_month() {
-        let odt =
-            utcdatetime_to_offsetdatetime(&UTCDateTime::from_ymddhms(1938, 9999, 15, 5, 0, 0, 0));
-
-        assert!(odt.is_err());
-    }
+    // UTCDateTime::format / parse_from_str
 
     #[test]
-    fn utcdatetime_to_offsetdatetime_bad_date() {
-        let odt =
-            utcdatetime_to_offsetdatetime(&UTCDateTime::from_ymddhms(1938, 7, 255, 5, 0, 0, 0));
+    fn utcdatetime_format_numeric_specifiers() {
+        let dt = UTCDateTime::from_ymdhms(2024, 6, 14, 9, 5, 3);
 
-        assert!(odt.is_err());
+        assert_eq!(dt.format("%Y-%m-%d %H:%M:%S").unwrap(), "2024-06-14 09:05:03");
     }
 
     #[test]
-    fn utcdatetime_to_offsetdatetime_bad_time() {
-        let odt =
-            utcdatetime_to_offsetdatetime(&UTCDateTime::from_ymddhms(1938, 7, 15, 5, 255, 0, 0));
+    fn utcdatetime_format_name_and_ordinal_specifiers() {
+        let dt = UTCDateTime::from_ymdhms(2024, 6, 14, 0, 0, 0);
 
-        assert!(odt.is_err());
+        assert_eq!(dt.format("%A, %B %d %Y").unwrap(), "Friday, June 14 2024");
+        assert_eq!(dt.format("day %j of %Y").unwrap(), "day 166 of 2024");
     }
 
     #[test]
-    fn offsetdatetime_to_utcdatetime_regular() {
-        let dt = offsetdatetime_to_utcdatetime(&time::OffsetDateTime::new_utc(
-            time::Date::from_calendar_date(1938, time::Month::July, 15).unwrap(),
-            time::Time::from_hms(0, 0, 0).unwrap(),
-        ));
+    fn utcdatetime_format_literal_percent() {
+        let dt = UTCDateTime::from_ymdhms(2024, 6, 14, 0, 0, 0);
 
-        assert_eq!(dt, UTCDateTime::from_ymddhms(1938, 7, 15, 5, 0, 0, 0));
+        assert_eq!(dt.format("100%%").unwrap(), "100%");
     }
 
-    // UTCDateTime
-
     #[test]
-    fn every_way_of_creating_utcdatetime_gives_same_result() {
-        let a = UTCDateTime {
-            year: 1968,
-            month: 2,
-            day: 27,
-            weekday: 2,
-            hour: 9,
-            minute: 10,
-            second: 0,
-        };
-        let b = UTCDateTime::from_ymdhms(1968, 2, 27, 9, 10, 0);
-        let c = UTCDateTime::from_ymddhms(1968, 2, 27, 2, 9, 10, 0);
-        let d = "1968-02-27T09:10:00Z".parse::<UTCDateTime>().unwrap();
-        let e = UTCDateTime::from_iso_string("1968-02-27T09:10:00Z").unwrap();
-        let f = UTCDateTime::try_from("1968-02-27T09:10:00Z").unwrap();
-        let g = UTCDateTime::from(time::OffsetDateTime::new_utc(
-            time::Date::from_calendar_date(1968, time::Month::February, 27).unwrap(),
-            time::Time::from_hms(9, 10, 0).unwrap(),
-        ));
-        let h = UTCDateTime::from_timestamp(-58_200_600).unwrap();
-        let i = UTCDateTime::from_julian_date(2_439_913.881_944_444_5);
+    fn utcdatetime_format_unknown_specifier() {
+        let dt = UTCDateTime::from_ymdhms(2024, 6, 14, 0, 0, 0);
 
-        assert!([b, c, d, e, f, g, h, i].iter().all(|x| *x == a));
+        assert!(dt.format("%Q").is_err());
     }
 
     #[test]
-    fn utcdatetime_from_iso_string_date() {
-        let a = UTCDateTime {
-            year: 2024,
-            month: 6,
-            day: 14,
-            weekday: 5,
-            hour: 0,
-            minute: 0,
-            second: 0,
-        };
-        let b = "2024-06-14".parse::<UTCDateTime>().unwrap();
+    fn utcdatetime_parse_from_str_round_trips() {
+        let dt = UTCDateTime::parse_from_str("2024-06-14 09:05:03", "%Y-%m-%d %H:%M:%S").unwrap();
 
-        assert!(std::iter::once(&b).all(|x| *x == a));
+        assert_eq!(dt, UTCDateTime::from_ymdhms(2024, 6, 14, 9, 5, 3));
     }
 
     #[test]
-    fn utcdatetime_from_iso_string_datetime() {
-        let a = UTCDateTime {
-            year: 2024,
-            month: 6,
-            day: 14,
-            weekday: 5,
-            hour: 21,
-            minute: 21,
-            second: 0,
-        };
-        let b = "2024-06-14T21:21:00".parse::<UTCDateTime>().unwrap();
-        let c = "2024-06-14T21:21:00Z".parse::<UTCDateTime>().unwrap();
-        let d = "2024-06-14T23:21:00+02:00".parse::<UTCDateTime>().unwrap();
+    fn utcdatetime_parse_from_str_date_only_pattern() {
+        let dt = UTCDateTime::parse_from_str("2024-06-14", "%Y-%m-%d").unwrap();
 
-        assert!([b, c, d].iter().all(|x| *x == a));
+        assert_eq!(dt, UTCDateTime::from_ymdhms(2024, 6, 14, 0, 0, 0));
     }
 
     #[test]
-    fn utcdatetime_try_from_timestamp_positive() {
-        let dt = UTCDateTime::from_timestamp(966_600_000).unwrap();
+    fn utcdatetime_parse_from_str_mismatched_input() {
+        let dt = UTCDateTime::parse_from_str("not a date", "%Y-%m-%d");
 
-        assert_eq!(dt, UTCDateTime::from_ymddhms(2000, 8, 18, 5, 12, 0, 0));
+        assert!(dt.is_err());
     }
 
     #[test]
-    fn utcdatetime_try_from_timestamp_zero() {
-        let dt = UTCDateTime::from_timestamp(0).unwrap();
+    fn utcdatetime_parse_from_str_unknown_specifier() {
+        let dt = UTCDateTime::parse_from_str("2024-06-14", "%Q");
 
-        assert_eq!(dt, UTCDateTime::from_ymddhms(1970, 1, 1, 4, 0, 0, 0));
+        assert!(dt.is_err());
     }
 
+    // FixedOffsetDateTime
+
     #[test]
-    fn utcdatetime_try_from_timestamp_negative() {
-        let dt = UTCDateTime::from_timestamp(-58_200_600).unwrap();
+    fn fixedoffsetdatetime_from_utc_positive_offset() {
+        let dt = FixedOffsetDateTime::from_utc(
+            &UTCDateTime::from_ymdhms(1964, 12, 20, 4, 35, 0),
+            3_600,
+        )
+        .unwrap();
 
-        assert_eq!(dt, UTCDateTime::from_ymddhms(1968, 2, 27, 2, 9, 10, 0));
+        assert_eq!(
+            dt,
+            FixedOffsetDateTime {
+                year: 1964,
+                month: 12,
+                day: 20,
+                weekday: 0,
+                hour: 5,
+                minute: 35,
+                second: 0,
+                offset_seconds: 3_600,
+            }
+        );
     }
 
     #[test]
-    fn utcdatetime_to_timestamp_positive() {
-        let dt = UTCDateTime::from_ymddhms(2000, 8, 18, 5, 12, 0, 0);
+    fn fixedoffsetdatetime_from_utc_negative_offset_crosses_midnight() {
+        let dt = FixedOffsetDateTime::from_utc(
+            &UTCDateTime::from_ymdhms(2024, 6, 15, 1, 0, 0),
+            -5 * 3_600,
+        )
+        .unwrap();
 
-        assert_eq!(dt.to_timestamp().unwrap(), 966_600_000);
+        assert_eq!(dt.year, 2024);
+        assert_eq!(dt.month, 6);
+        assert_eq!(dt.day, 14);
+        assert_eq!(dt.hour, 20);
+        assert_eq!(dt.offset_seconds, -5 * 3_600);
     }
 
     #[test]
-    fn utcdatetime_to_timestamp_zero_() {
-        let dt = UTCDateTime::from_ymddhms(1970, 1, 1, 4, 0, 0, 0);
+    fn fixedoffsetdatetime_to_utc_round_trips() {
+        let utc = UTCDateTime::from_ymdhms(1964, 12, 20, 4, 35, 0);
+        let dt = FixedOffsetDateTime::from_utc(&utc, 3_600).unwrap();
 
-        assert_eq!(dt.to_timestamp().unwrap(), 0);
+        assert_eq!(dt.to_utc(), utc);
     }
 
     #[test]
-    fn utcdatetime_to_timestamp_negative_() {
-        let dt = UTCDateTime::from_ymddhms(1968, 2, 27, 2, 9, 10, 0);
+    fn fixedoffsetdatetime_from_iso_string_preserves_offset() {
+        let dt: FixedOffsetDateTime = "1964-12-20T05:35:00+01:00".parse().unwrap();
 
-        assert_eq!(dt.to_timestamp().unwrap(), -58_200_600);
+        assert_eq!(dt.offset_seconds, 3_600);
+        assert_eq!(dt.to_string(), "1964-12-20T05:35:00+01:00");
     }
 
     #[test]
-    fn utcdatetime_from_julian_date_regular() {
-        let dt = UTCDateTime::from_julian_date(2_460_473.196_55);
+    fn fixedoffsetdatetime_from_iso_string_negative_offset() {
+        let dt: FixedOffsetDateTime = "1964-12-20T05:35:00-05:30".parse().unwrap();
 
-        assert_eq!(dt, UTCDateTime::from_ymdhms(2024, 6, 11, 16, 43, 2));
+        assert_eq!(dt.offset_seconds, -(5 * 3_600 + 30 * 60));
+        assert_eq!(dt.to_string(), "1964-12-20T05:35:00-05:30");
     }
 
     #[test]
-    fn utcdatetime_from_julian_date_zero() {
-        let dt = UTCDateTime::from_julian_date(0.0);
+    fn fixedoffsetdatetime_from_iso_string_zulu_is_zero_offset() {
+        let dt: FixedOffsetDateTime = "1964-12-20T05:35:00Z".parse().unwrap();
 
-        assert_eq!(dt, UTCDateTime::from_ymddhms(-4712, 1, 1, 1, 12, 0, 0));
+        assert_eq!(dt.offset_seconds, 0);
+        assert_eq!(dt.to_string(), "1964-12-20T05:35:00+00:00");
     }
 
     #[test]
-    fn utcdatetime_to_julian_date_regular() {
-        let dt = UTCDateTime::from_ymdhms(2024, 6, 11, 16, 43, 2);
+    fn fixedoffsetdatetime_from_iso_string_invalid() {
+        let dt = FixedOffsetDateTime::from_iso_string("not a date");
 
-        assert_almost_eq!(dt.to_julian_date(), 2_460_473.196_550_925_7);
+        assert!(dt.is_err());
     }
 
     #[test]
-    fn utcdatetime_to_julian_date_zero() {
-        let dt = UTCDateTime::from_ymddhms(-4712, 1, 1, 1, 12, 0, 0);
+    fn fixedoffsetdatetime_weekday_monthname_dayname() {
+        let dt: FixedOffsetDateTime = "1964-12-20T05:35:00+01:00".parse().unwrap();
 
-        assert_almost_eq!(dt.to_julian_date(), 0.0);
+        assert_eq!(dt.weekday(), Weekday::Sunday);
+        assert_eq!(dt.monthname(), "December");
+        assert_eq!(dt.dayname(), "Sunday");
     }
 
     #[test]
-    fn utcdatetime_to_civil_julian_date_regular() {
-        let dt = UTCDateTime::from_ymdhms(2024, 6, 11, 16, 43, 2);
+    fn fixedoffsetdatetime_dayname_uses_local_components_not_utc() {
+        // Local Saturday night, but the offset pushes the UTC instant
+        // into Sunday. `dayname`/`weekday` should follow the local date.
+        let dt: FixedOffsetDateTime = "1964-12-19T23:30:00-05:00".parse().unwrap();
+
+        assert_eq!(dt.weekday(), Weekday::Saturday);
+        assert_eq!(dt.dayname(), "Saturday");
+        assert_eq!(dt.to_utc().weekday(), Weekday::Sunday);
+    }
 
-        assert_almost_eq!(dt.to_civil_julian_date(), 2_460_473.696_550_925_7);
+    // DstRule
+
+    fn test_dst_rule() -> DstRule {
+        // Standard +08:00, daylight +09:00, spring forward on 30 March
+        // and fall back on 26 October. Fixed at specific dates rather
+        // than "last/first Sunday" since `DstRule` only supports fixed
+        // calendar dates.
+        DstRule {
+            standard_offset_seconds: 8 * 3_600,
+            daylight_offset_seconds: 9 * 3_600,
+            dst_start: (3, 30),
+            dst_end: (10, 26),
+        }
     }
 
     #[test]
-    fn utcdatetime_to_civil_julian_date_zero() {
-        let dt = UTCDateTime::from_ymddhms(-4712, 1, 1, 1, 0, 0, 0);
+    fn dstrule_utc_to_local_in_winter_uses_standard_offset() {
+        let rule = test_dst_rule();
+        let utc = UTCDateTime::from_ymdhms(2024, 1, 1, 0, 0, 0);
 
-        assert_almost_eq!(dt.to_civil_julian_date(), 0.0);
+        let local = rule.utc_to_local(&utc).unwrap();
+
+        assert_eq!(local.hour, 8);
     }
 
     #[test]
-    fn utcdatetime_parse_invalid_string() {
-        let dt = "Sat. 11 May 2024".parse::<UTCDateTime>();
+    fn dstrule_utc_to_local_in_summer_uses_daylight_offset() {
+        let rule = test_dst_rule();
+        let utc = UTCDateTime::from_ymdhms(2024, 6, 1, 0, 0, 0);
 
-        assert!(dt.is_err());
+        let local = rule.utc_to_local(&utc).unwrap();
+
+        assert_eq!(local.hour, 9);
     }
 
     #[test]
-    fn utcdatetime_from_invalid_string() {
-        let dt = UTCDateTime::try_from("Sat. 11 May 2024");
+    fn dstrule_local_to_utc_unambiguous() {
+        let rule = test_dst_rule();
+        let local = LocalDateTime {
+            year: 2024,
+            month: 1,
+            day: 1,
+            weekday: 99,
+            hour: 8,
+            minute: 0,
+            second: 0,
+        };
 
-        assert!(dt.is_err());
+        let result = rule.local_to_utc(&local).unwrap();
+
+        assert_eq!(
+            result,
+            LocalResult::Single(UTCDateTime::from_ymdhms(2024, 1, 1, 0, 0, 0))
+        );
     }
 
     #[test]
-    fn utcdatetime_display() {
-        let dt = UTCDateTime::from_ymddhms(1968, 2, 27, 2, 9, 10, 0);
+    fn dstrule_local_to_utc_spring_forward_gap_is_none() {
+        let rule = test_dst_rule();
+        // 02:00-02:59 standard local time on the spring-forward day
+        // never occurs: the clock jumps straight from 02:00 to 03:00.
+        let local = LocalDateTime {
+            year: 2024,
+            month: 3,
+            day: 30,
+            weekday: 99,
+            hour: 2,
+            minute: 30,
+            second: 0,
+        };
 
-        assert_eq!(dt.to_string(), "1968-02-27T09:10:00Z");
+        assert_eq!(rule.local_to_utc(&local).unwrap(), LocalResult::None);
     }
 
     #[test]
-    fn utcdatetime_to_offsetdatetime_() {
-        let odt =
-            time::OffsetDateTime::try_from(&UTCDateTime::from_ymddhms(1938, 7, 15, 5, 0, 0, 0))
-                .unwrap();
+    fn dstrule_local_to_utc_fall_back_hour_is_ambiguous() {
+        let rule = test_dst_rule();
+        // 01:00-01:59 daylight local time on the fall-back day occurs
+        // twice: once before, once after the clock falls back to 01:00.
+        let local = LocalDateTime {
+            year: 2024,
+            month: 10,
+            day: 26,
+            weekday: 99,
+            hour: 1,
+            minute: 30,
+            second: 0,
+        };
+
+        let result = rule.local_to_utc(&local).unwrap();
 
         assert_eq!(
-            odt,
-            time::OffsetDateTime::new_utc(
-                time::Date::from_calendar_date(1938, time::Month::July, 15).unwrap(),
-                time::Time::from_hms(0, 0, 0).unwrap()
+            result,
+            LocalResult::Ambiguous(
+                UTCDateTime::from_ymdhms(2024, 10, 25, 16, 30, 0),
+                UTCDateTime::from_ymdhms(2024, 10, 25, 17, 30, 0),
             )
         );
     }
+
+    #[test]
+    fn dstrule_utc_to_local_round_trips_through_local_to_utc() {
+        let rule = test_dst_rule();
+        let utc = UTCDateTime::from_ymdhms(2024, 6, 1, 3, 15, 0);
+
+        let local = rule.utc_to_local(&utc).unwrap();
+        let result = rule.local_to_utc(&local).unwrap();
+
+        assert_eq!(result, LocalResult::Single(utc));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,130 @@
+// Instantaneous topocentric altitude/azimuth of the Moon, for a
+// specific observer at a specific instant.
+//
+// Shares the equatorial-coordinate and sidereal-time machinery built
+// for moonrise/moonset ([`super::rise_set`]), but instead of sweeping a
+// whole day looking for crossings, it evaluates a single instant and
+// applies the topocentric parallax correction (Jean Meeus,
+// *Astronomical Algorithms*, Chapter 40).
+
+use super::rise_set::{equatorial_coordinates_at, greenwich_sidereal_time};
+use super::{ForDateTime, MoonPhase, UTCDateTime};
+
+/// The Moon's position in the sky as seen by a specific observer at a
+/// specific instant: altitude and azimuth, corrected for topocentric
+/// parallax (the shift caused by the observer standing on Earth's
+/// surface rather than at its centre).
+#[derive(Clone, Debug, PartialEq)]
+pub struct MoonPosition {
+    /// Altitude above the horizon, in degrees (negative if below it).
+    pub altitude: f64,
+    /// Azimuth, in degrees, measured clockwise from north.
+    pub azimuth: f64,
+    /// Topocentric distance to the Moon's centre, in kilometres: the
+    /// geocentric distance less Earth's radius times the sine of the
+    /// geocentric altitude, so shorter while the Moon is above the
+    /// horizon and longer while it is below (by at most Earth's radius).
+    pub distance_km: f64,
+}
+
+/// Compute the Moon's altitude, azimuth, and topocentric distance at
+/// `datetime`, as seen by an observer at `latitude`/`longitude`, both in
+/// degrees (`longitude` positive east of Greenwich).
+///
+/// The geocentric altitude is corrected for the Moon's horizontal
+/// parallax (π), using the "parallax in altitude" approximation
+/// `h_topocentric ≈ h_geocentric - π·cos(h_geocentric)`: the correction
+/// is largest (up to ~1°) near the horizon and vanishes at the zenith.
+/// Azimuth is left uncorrected, since parallax shifts it by a
+/// negligible amount at this precision.
+///
+/// # Examples
+///
+/// ```rust
+/// # use moontool::moon::{moon_position, UTCDateTime};
+/// let datetime = UTCDateTime::from_ymdhms(2024, 6, 14, 21, 0, 0);
+/// let position = moon_position(51.4779, -0.0015, &datetime); // Royal Observatory, Greenwich.
+/// assert!((-90.0..=90.0).contains(&position.altitude));
+/// assert!((0.0..360.0).contains(&position.azimuth));
+/// ```
+#[must_use]
+pub fn moon_position(latitude: f64, longitude: f64, datetime: &UTCDateTime) -> MoonPosition {
+    let jd = datetime.to_julian_date();
+    let (ra, dec) = equatorial_coordinates_at(jd);
+    let mphase = MoonPhase::for_datetime(datetime);
+
+    let gst = greenwich_sidereal_time(jd);
+    let hour_angle = (gst + longitude - ra).to_radians();
+
+    let lat = latitude.to_radians();
+    let dec_rad = dec.to_radians();
+
+    let geocentric_altitude =
+        (lat.sin() * dec_rad.sin() + lat.cos() * dec_rad.cos() * hour_angle.cos()).asin();
+    let azimuth = hour_angle
+        .sin()
+        .atan2(hour_angle.cos() * lat.sin() - dec_rad.tan() * lat.cos());
+
+    let parallax = mphase.parallax.to_radians();
+    let altitude = geocentric_altitude - parallax * geocentric_altitude.cos();
+
+    MoonPosition {
+        altitude: altitude.to_degrees(),
+        azimuth: super::fixangle(azimuth.to_degrees() + 180.0),
+        distance_km: mphase.distance_to_earth_km - super::EARTHRAD * geocentric_altitude.sin(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn moon_position_altitude_is_within_range() {
+        let datetime = UTCDateTime::from_ymdhms(2024, 6, 14, 21, 0, 0);
+        let position = moon_position(51.4779, -0.0015, &datetime);
+
+        assert!((-90.0..=90.0).contains(&position.altitude));
+    }
+
+    #[test]
+    fn moon_position_azimuth_is_within_range() {
+        let datetime = UTCDateTime::from_ymdhms(2024, 6, 14, 21, 0, 0);
+        let position = moon_position(51.4779, -0.0015, &datetime);
+
+        assert!((0.0..360.0).contains(&position.azimuth));
+    }
+
+    #[test]
+    fn moon_position_parallax_correction_lowers_altitude_near_horizon() {
+        // Near the horizon, the topocentric correction should pull the
+        // apparent altitude down relative to the geocentric one.
+        let datetime = UTCDateTime::from_ymdhms(2024, 6, 14, 0, 0, 0);
+        let (ra, dec) = equatorial_coordinates_at(datetime.to_julian_date());
+        let mphase = MoonPhase::for_datetime(&datetime);
+        let gst = greenwich_sidereal_time(datetime.to_julian_date());
+        let hour_angle = (gst - ra).to_radians();
+        let lat = 0.0_f64.to_radians();
+        let dec_rad = dec.to_radians();
+        let geocentric_altitude =
+            (lat.sin() * dec_rad.sin() + lat.cos() * dec_rad.cos() * hour_angle.cos()).asin();
+
+        let position = moon_position(0.0, 0.0, &datetime);
+
+        if geocentric_altitude.to_degrees().abs() < 5.0 {
+            assert!(position.altitude < geocentric_altitude.to_degrees());
+        }
+
+        assert!(mphase.parallax > 0.0);
+    }
+
+    #[test]
+    fn moon_position_distance_is_close_to_geocentric_distance() {
+        let datetime = UTCDateTime::from_ymdhms(2024, 6, 14, 21, 0, 0);
+        let mphase = MoonPhase::for_datetime(&datetime);
+        let position = moon_position(51.4779, -0.0015, &datetime);
+
+        let delta = (position.distance_km - mphase.distance_to_earth_km).abs();
+        assert!(delta <= super::super::EARTHRAD);
+    }
+}
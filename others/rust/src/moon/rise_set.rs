@@ -0,0 +1,261 @@
+// Moonrise, upper-transit (culmination), and moonset times for a
+// geographic observer.
+//
+// Follows the low-precision sweep method from Jean Meeus's
+// *Astronomical Algorithms*, Chapter 15: get the Moon's apparent
+// equatorial coordinates at 0h, 12h, and 24h UT, interpolate them
+// hour by hour, and locate where the altitude crosses the standard
+// altitude (rise/set) or the local hour angle crosses zero (transit).
+
+use super::{fixangle, ForDateTime, MoonPhase, UTCDateTime};
+
+/// Mean obliquity of the ecliptic, in degrees (J2000.0 value; treated as
+/// constant, like the other epoch-1980/J2000 constants this module
+/// relies on).
+const OBLIQUITY: f64 = 23.439_291;
+
+/// Standard altitude at which moonrise/moonset is reckoned to occur, in
+/// degrees: the Moon's horizontal parallax less its mean semidiameter
+/// and the atmospheric refraction at the horizon (the Moon's parallax is
+/// large enough, unlike the Sun's, that it can't be neglected even at
+/// this precision).
+const STANDARD_ALTITUDE: f64 = 0.125;
+
+/// Sidereal angular rate, in degrees per UT hour: the sky turns
+/// slightly faster than the Sun (360.985_647_366_29°/day).
+const SIDEREAL_RATE_PER_HOUR: f64 = 360.985_647_366_29 / 24.0;
+
+/// Moonrise, upper-transit (culmination), and moonset for one calendar
+/// day, as seen by an observer at a given latitude/longitude.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MoonRiseSet {
+    /// `None` if the Moon never rises above [`STANDARD_ALTITUDE`] on
+    /// this day.
+    pub rise: Option<UTCDateTime>,
+    /// Upper transit (culmination), i.e. the Moon's highest point above
+    /// the horizon. `None` only in the degenerate case where the local
+    /// hour angle doesn't cross zero within the day's hourly sweep.
+    pub transit: Option<UTCDateTime>,
+    /// `None` if the Moon never sets below [`STANDARD_ALTITUDE`] on
+    /// this day (circumpolar).
+    pub set: Option<UTCDateTime>,
+}
+
+/// Compute moonrise, upper-transit, and moonset for `date` (read as a
+/// UTC calendar day — only the year/month/day fields are used) as seen
+/// by an observer at `latitude`/`longitude`, both in degrees
+/// (`longitude` positive east of Greenwich).
+///
+/// # Examples
+///
+/// ```rust
+/// # use moontool::moon::{moon_rise_set, UTCDateTime};
+/// let date = UTCDateTime::from_ymdhms(2024, 6, 14, 0, 0, 0);
+/// let rs = moon_rise_set(51.4779, -0.0015, &date); // Royal Observatory, Greenwich.
+/// assert!(rs.transit.is_some());
+/// ```
+#[must_use]
+pub fn moon_rise_set(latitude: f64, longitude: f64, date: &UTCDateTime) -> MoonRiseSet {
+    let midnight = UTCDateTime::from_ymdhms(date.year, date.month, date.day, 0, 0, 0);
+    let jd0 = midnight.to_julian_date();
+
+    let (ra0, dec0) = equatorial_coordinates_at(jd0);
+    let (ra12, dec12) = equatorial_coordinates_at(jd0 + 0.5);
+    let (ra24, dec24) = equatorial_coordinates_at(jd0 + 1.0);
+
+    // Unwrap right ascension for continuity across the day, so
+    // quadratic interpolation doesn't see a spurious ~360° jump.
+    let ra12 = unwrap_near(ra0, ra12);
+    let ra24 = unwrap_near(ra12, ra24);
+
+    let gst0 = greenwich_sidereal_time(jd0);
+
+    let mut altitude = [0.0_f64; 25];
+    let mut hour_angle = [0.0_f64; 25];
+
+    for (hour, (alt, h_angle)) in altitude.iter_mut().zip(hour_angle.iter_mut()).enumerate() {
+        let t = hour as f64;
+        let n = (t - 12.0) / 12.0;
+
+        let ra = quadratic_interpolate(ra0, ra12, ra24, n);
+        let dec = quadratic_interpolate(dec0, dec12, dec24, n);
+
+        let gst = fixangle(gst0 + t * SIDEREAL_RATE_PER_HOUR);
+        let h = fixangle(gst + longitude - ra);
+
+        *alt = altitude_degrees(latitude, dec, h);
+        *h_angle = if h > 180.0 { h - 360.0 } else { h };
+    }
+
+    let rise = find_crossing(&altitude, STANDARD_ALTITUDE, true);
+    let set = find_crossing(&altitude, STANDARD_ALTITUDE, false);
+    let transit = find_zero_crossing(&hour_angle);
+
+    MoonRiseSet {
+        rise: rise.map(|t| UTCDateTime::from_julian_date(jd0 + t / 24.0)),
+        transit: transit.map(|t| UTCDateTime::from_julian_date(jd0 + t / 24.0)),
+        set: set.map(|t| UTCDateTime::from_julian_date(jd0 + t / 24.0)),
+    }
+}
+
+/// The Moon's apparent right ascension (α) and declination (δ) at
+/// `jd`, converted from [`MoonPhase`]'s geocentric ecliptic
+/// coordinates via the obliquity of the ecliptic. Both in degrees.
+pub(super) fn equatorial_coordinates_at(jd: f64) -> (f64, f64) {
+    let mphase = MoonPhase::for_datetime(&UTCDateTime::from_julian_date(jd));
+    let lambda = mphase.ecliptic_longitude.to_radians();
+    let beta = mphase.ecliptic_latitude.to_radians();
+    let obliquity = OBLIQUITY.to_radians();
+
+    let dec = (beta.sin() * obliquity.cos() + beta.cos() * obliquity.sin() * lambda.sin()).asin();
+    let ra = (lambda.sin() * obliquity.cos() - beta.tan() * obliquity.sin()).atan2(lambda.cos());
+
+    (fixangle(ra.to_degrees()), dec.to_degrees())
+}
+
+/// Greenwich mean sidereal time at `jd`, in degrees `[0;360)`.
+pub(super) fn greenwich_sidereal_time(jd: f64) -> f64 {
+    let t = (jd - 2_451_545.0) / 36_525.0;
+    let gst = 280.460_618_37
+        + 360.985_647_366_29 * (jd - 2_451_545.0)
+        + 0.000_387_933 * t * t
+        - t * t * t / 38_710_000.0;
+    fixangle(gst)
+}
+
+/// Altitude of a body at declination `dec` and local hour angle
+/// `hour_angle` (both in degrees), as seen from `latitude` (degrees),
+/// with no parallax correction applied. In degrees.
+pub(super) fn altitude_degrees(latitude: f64, dec: f64, hour_angle: f64) -> f64 {
+    let lat = latitude.to_radians();
+    let dec = dec.to_radians();
+    let h = hour_angle.to_radians();
+    (lat.sin() * dec.sin() + lat.cos() * dec.cos() * h.cos()).asin().to_degrees()
+}
+
+/// Adjust `current` by whole turns of 360° so it falls within 180° of
+/// `prev`, undoing the 0°/360° wraparound before interpolating.
+fn unwrap_near(prev: f64, current: f64) -> f64 {
+    let mut value = current;
+    while value - prev > 180.0 {
+        value -= 360.0;
+    }
+    while value - prev < -180.0 {
+        value += 360.0;
+    }
+    value
+}
+
+/// Meeus's 3-point quadratic interpolation (Ch. 3): given equally
+/// spaced samples `y1`, `y2`, `y3` and `n` the fraction of the
+/// half-interval from `y2` (`-1` at `y1`, `0` at `y2`, `+1` at `y3`),
+/// interpolate the value at `n`.
+fn quadratic_interpolate(y1: f64, y2: f64, y3: f64, n: f64) -> f64 {
+    let a = y2 - y1;
+    let b = y3 - y2;
+    let c = b - a;
+    y2 + (n / 2.0) * (a + b + n * c)
+}
+
+/// Find the fractional hour (within `[0;24]`) at which `samples`
+/// crosses `threshold`, rising if `rising`, falling otherwise. `None`
+/// if it never does.
+fn find_crossing(samples: &[f64; 25], threshold: f64, rising: bool) -> Option<f64> {
+    for i in 0..24 {
+        let (before, after) = (samples[i], samples[i + 1]);
+        let crosses = if rising {
+            before < threshold && after >= threshold
+        } else {
+            before >= threshold && after < threshold
+        };
+        if crosses {
+            let fraction = (threshold - before) / (after - before);
+            return Some(i as f64 + fraction);
+        }
+    }
+    None
+}
+
+/// Find the fractional hour (within `[0;24]`) at which `samples`
+/// (expected to be monotonically increasing local hour angles in
+/// `[-180;180]`) crosses zero, i.e. upper transit. `None` if it never
+/// does within the day's sweep.
+fn find_zero_crossing(samples: &[f64; 25]) -> Option<f64> {
+    for i in 0..24 {
+        let (before, after) = (samples[i], samples[i + 1]);
+        if before <= 0.0 && after > 0.0 {
+            let fraction = -before / (after - before);
+            return Some(i as f64 + fraction);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn moon_rise_set_always_has_a_transit() {
+        let date = UTCDateTime::from_ymdhms(2024, 6, 14, 0, 0, 0);
+        let rs = moon_rise_set(51.4779, -0.0015, &date);
+
+        assert!(rs.transit.is_some());
+    }
+
+    #[test]
+    fn moon_rise_set_transit_is_within_the_day() {
+        let date = UTCDateTime::from_ymdhms(2024, 6, 14, 0, 0, 0);
+        let rs = moon_rise_set(51.4779, -0.0015, &date);
+
+        let transit = rs.transit.unwrap();
+        assert_eq!(transit.year, 2024);
+        assert_eq!(transit.month, 6);
+        assert!(transit.day == 14 || transit.day == 15);
+    }
+
+    #[test]
+    fn moon_rise_set_near_north_pole_can_be_permanently_above_or_below_horizon() {
+        // Near the pole, the Moon can stay below the horizon (or above
+        // it) for the entire day, depending on its declination.
+        let date = UTCDateTime::from_ymdhms(2024, 1, 1, 0, 0, 0);
+        let rs = moon_rise_set(89.9, 0.0, &date);
+
+        assert!(rs.rise.is_none() || rs.set.is_none());
+    }
+
+    #[test]
+    fn unwrap_near_brings_value_within_180_degrees() {
+        assert!((unwrap_near(350.0, 5.0) - 365.0).abs() < 1e-9);
+        assert!((unwrap_near(5.0, 350.0) - (-10.0)).abs() < 1e-9);
+        assert!((unwrap_near(100.0, 110.0) - 110.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn quadratic_interpolate_at_anchors_returns_anchor_values() {
+        assert!((quadratic_interpolate(1.0, 2.0, 3.0, -1.0) - 1.0).abs() < 1e-9);
+        assert!((quadratic_interpolate(1.0, 2.0, 3.0, 0.0) - 2.0).abs() < 1e-9);
+        assert!((quadratic_interpolate(1.0, 2.0, 3.0, 1.0) - 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn find_crossing_finds_rising_and_falling_edges() {
+        // A plateau above threshold from hour 10 to hour 18: rises at
+        // the 9/10 boundary, falls at the 18/19 boundary.
+        let mut samples = [-1.0; 25];
+        for sample in &mut samples[10..=18] {
+            *sample = 1.0;
+        }
+
+        assert_eq!(find_crossing(&samples, 0.0, true), Some(9.5));
+        assert_eq!(find_crossing(&samples, 0.0, false), Some(18.5));
+    }
+
+    #[test]
+    fn find_crossing_returns_none_if_never_crossed() {
+        let samples = [-1.0; 25];
+
+        assert_eq!(find_crossing(&samples, 0.0, true), None);
+        assert_eq!(find_crossing(&samples, 0.0, false), None);
+    }
+}